@@ -52,8 +52,14 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
     let type_ident = vec.vec_type.type_ident();
     let type_idents = vec![type_ident.clone(); fields.len()];
     let hash = (vec.vec_type != &VecType::F).then_some(quote! {Hash,});
+    // Vec3 is Luau's native vector there, see src/luau.rs
+    let lua_cfg = if vec.vec_type == &VecType::F && vec.fields.len() == 3 {
+        quote! {#[cfg(all(feature = "lua", not(feature = "luau")))]}
+    } else {
+        quote! {#[cfg(feature = "lua")]}
+    };
     let neg = (vec.vec_type != &VecType::U).then_some(quote! {
-        impl std::ops::Neg for #name {
+        impl core::ops::Neg for #name {
             type Output = Self;
             fn neg(self) -> Self {
                 Self {
@@ -107,10 +113,22 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
             name,
             vec.vec_type.type_ident()
         );
-        quote! {
-            #field_ident: mlua::ErrorContext::with_context(table.raw_get(#id), |_| {
-                #set_err
-            })?,
+        if vec.vec_type == &VecType::F {
+            // Lua 5.4 keeps integers apart from floats, accept both subtypes
+            quote! {
+                #field_ident: match mlua::ErrorContext::with_context(
+                    table.raw_get::<mlua::Value>(#id), |_| { #set_err })? {
+                    mlua::Value::Integer(value) => value as f32,
+                    mlua::Value::Number(value) => value as f32,
+                    _ => return Err(lua_error::lua_error!(#set_err)),
+                },
+            }
+        } else {
+            quote! {
+                #field_ident: mlua::ErrorContext::with_context(table.raw_get(#id), |_| {
+                    #set_err
+                })?,
+            }
         }
     });
     let set_fields = vec.fields.iter().enumerate().map(|(id, f)| {
@@ -140,7 +158,7 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
     let float_fns = (vec.vec_type == &VecType::F).then_some(quote! {
         pub fn floor(self) -> Self {
             Self {
-                #(#fields: self.#fields.floor()),*
+                #(#fields: crate::math::floor(self.#fields)),*
             }
         }
         pub fn fract(self) -> Self {
@@ -151,17 +169,17 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
         }
         pub fn trunc(self) -> Self {
             Self {
-                #(#fields: self.#fields.trunc()),*
+                #(#fields: crate::math::trunc(self.#fields)),*
             }
         }
         pub fn ceil(self) -> Self {
             Self {
-                #(#fields: self.#fields.ceil()),*
+                #(#fields: crate::math::ceil(self.#fields)),*
             }
         }
         pub fn round(self) -> Self {
             Self {
-                #(#fields: self.#fields.round()),*
+                #(#fields: crate::math::round(self.#fields)),*
             }
         }
         pub fn lerp(self, rhs: Self, s: f32) -> Self {
@@ -200,22 +218,44 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
             #(self.#fields * other.#fields)+*
         }
     });
-    let abs_fn = (vec.vec_type != &VecType::U).then_some(quote! {
-        pub fn abs(self) -> Self {
+    let abs_fn = match vec.vec_type {
+        VecType::U => None,
+        VecType::I => Some(quote! {
+            pub fn abs(self) -> Self {
+                Self {
+                    #(#fields: self.#fields.abs()),*
+                }
+            }
+        }),
+        VecType::F => Some(quote! {
+            pub fn abs(self) -> Self {
+                Self {
+                    #(#fields: crate::math::abs(self.#fields)),*
+                }
+            }
+        }),
+    };
+
+    let cross_fn = (vec.vec_type == &VecType::F && vec.fields.len() == 3).then_some(quote! {
+        pub fn cross(self, rhs: Self) -> Self {
             Self {
-                #(#fields: self.#fields.abs()),*
+                x: self.y * rhs.z - self.z * rhs.y,
+                y: self.z * rhs.x - self.x * rhs.z,
+                z: self.x * rhs.y - self.y * rhs.x,
             }
         }
     });
 
     quote! {
-        #[derive(Default, Debug, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize, #hash)]
+        #[derive(Default, Debug, PartialEq, Clone, Copy, #hash)]
+        #[repr(C)]
         pub struct #name {
             #(
                 pub #fields: #type_ident,
             )*
         }
 
+        #lua_cfg
         impl mlua::FromLua for #name {
             fn from_lua(value: mlua::Value, _lua: &mlua::Lua) -> mlua::Result<Self> {
                 let table = value.as_table().ok_or_else(|| {
@@ -231,6 +271,7 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
             }
         }
 
+        #lua_cfg
         impl mlua::IntoLua for #name {
             fn into_lua(self, lua: &mlua::Lua) -> mlua::Result<mlua::Value> {
                 let table = lua.create_table()?;
@@ -260,7 +301,7 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
                 }
             }
             pub fn length(self) -> f32 {
-                (#((self.#fields as f32) * (self.#fields as f32))+*).sqrt()
+                crate::math::sqrt(#((self.#fields as f32) * (self.#fields as f32))+*)
             }
             pub fn length_squared(self) -> f32 {
                 #((self.#fields as f32) * (self.#fields as f32))+*
@@ -272,9 +313,10 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
             }
             #float_fns
             #abs_fn
+            #cross_fn
         }
 
-        impl std::ops::Add<#name> for #name {
+        impl core::ops::Add<#name> for #name {
             type Output = Self;
 
             fn add(self, other: Self) -> Self {
@@ -284,13 +326,13 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
             }
         }
 
-        impl std::ops::AddAssign<#name> for #name {
+        impl core::ops::AddAssign<#name> for #name {
             fn add_assign(&mut self, other: Self) {
                 #(self.#fields += other.#fields;)*
             }
         }
 
-        impl std::ops::Add<#type_ident> for #name {
+        impl core::ops::Add<#type_ident> for #name {
             type Output = Self;
 
             fn add(self, other: #type_ident) -> Self {
@@ -300,13 +342,13 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
             }
         }
 
-        impl std::ops::AddAssign<#type_ident> for #name {
+        impl core::ops::AddAssign<#type_ident> for #name {
             fn add_assign(&mut self, other: #type_ident) {
                 #(self.#fields += other;)*
             }
         }
 
-        impl std::ops::Sub<#name> for #name {
+        impl core::ops::Sub<#name> for #name {
             type Output = Self;
 
             fn sub(self, other: Self) -> Self {
@@ -316,13 +358,13 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
             }
         }
 
-        impl std::ops::SubAssign<#name> for #name {
+        impl core::ops::SubAssign<#name> for #name {
             fn sub_assign(&mut self, other: Self) {
                 #(self.#fields -= other.#fields;)*
             }
         }
 
-        impl std::ops::Sub<#type_ident> for #name {
+        impl core::ops::Sub<#type_ident> for #name {
             type Output = Self;
 
             fn sub(self, other: #type_ident) -> Self {
@@ -332,13 +374,13 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
             }
         }
 
-        impl std::ops::SubAssign<#type_ident> for #name {
+        impl core::ops::SubAssign<#type_ident> for #name {
             fn sub_assign(&mut self, other: #type_ident) {
                 #(self.#fields -= other;)*
             }
         }
 
-        impl std::ops::Mul<#name> for #name {
+        impl core::ops::Mul<#name> for #name {
             type Output = Self;
 
             fn mul(self, other: Self) -> Self {
@@ -348,13 +390,13 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
             }
         }
 
-        impl std::ops::MulAssign<#name> for #name {
+        impl core::ops::MulAssign<#name> for #name {
             fn mul_assign(&mut self, other: Self) {
                 #(self.#fields *= other.#fields;)*
             }
         }
 
-        impl std::ops::Mul<#type_ident> for #name {
+        impl core::ops::Mul<#type_ident> for #name {
             type Output = Self;
 
             fn mul(self, other: #type_ident) -> Self {
@@ -364,13 +406,13 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
             }
         }
 
-        impl std::ops::MulAssign<#type_ident> for #name {
+        impl core::ops::MulAssign<#type_ident> for #name {
             fn mul_assign(&mut self, other: #type_ident) {
                 #(self.#fields *= other;)*
             }
         }
 
-        impl std::ops::Div<#name> for #name {
+        impl core::ops::Div<#name> for #name {
             type Output = Self;
 
             fn div(self, other: Self) -> Self {
@@ -380,13 +422,13 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
             }
         }
 
-        impl std::ops::DivAssign<#name> for #name {
+        impl core::ops::DivAssign<#name> for #name {
             fn div_assign(&mut self, other: Self) {
                 #(self.#fields /= other.#fields;)*
             }
         }
 
-        impl std::ops::Div<#type_ident> for #name {
+        impl core::ops::Div<#type_ident> for #name {
             type Output = Self;
 
             fn div(self, other: #type_ident) -> Self {
@@ -396,7 +438,7 @@ fn gen_vec(vec: GenericVec) -> TokenStream {
             }
         }
 
-        impl std::ops::DivAssign<#type_ident> for #name {
+        impl core::ops::DivAssign<#type_ident> for #name {
             fn div_assign(&mut self, other: #type_ident) {
                 #(self.#fields /= other;)*
             }
@@ -0,0 +1,435 @@
+//! Lua bindings: the embedded Lua sources of the meth types and their installation.
+
+use std::{borrow::Cow, path::Path};
+
+use mlua::IntoLuaMulti;
+
+/// Lua files defining the meth types in load order, keyed by their path in this repository.
+pub const TYPE_SOURCES: &[(&str, &str)] = &[
+    ("src/lua/meth/vec2.lua", include_str!("lua/meth/vec2.lua")),
+    ("src/lua/meth/ivec2.lua", include_str!("lua/meth/ivec2.lua")),
+    VEC3_SOURCE,
+    ("src/lua/meth/ivec3.lua", include_str!("lua/meth/ivec3.lua")),
+    ("src/lua/meth/vec4.lua", include_str!("lua/meth/vec4.lua")),
+    ("src/lua/meth/ivec4.lua", include_str!("lua/meth/ivec4.lua")),
+    ("src/lua/meth/mat2.lua", include_str!("lua/meth/mat2.lua")),
+    ("src/lua/meth/mat3.lua", include_str!("lua/meth/mat3.lua")),
+    ("src/lua/meth/mat4.lua", include_str!("lua/meth/mat4.lua")),
+    ("src/lua/meth/rect.lua", include_str!("lua/meth/rect.lua")),
+    ("src/lua/meth/irect.lua", include_str!("lua/meth/irect.lua")),
+    ("src/lua/meth/aabb3.lua", include_str!("lua/meth/aabb3.lua")),
+    ("src/lua/meth/ray2.lua", include_str!("lua/meth/ray2.lua")),
+    ("src/lua/meth/ray3.lua", include_str!("lua/meth/ray3.lua")),
+    ("src/lua/meth/plane.lua", include_str!("lua/meth/plane.lua")),
+    (
+        "src/lua/meth/sphere.lua",
+        include_str!("lua/meth/sphere.lua"),
+    ),
+    (
+        "src/lua/meth/circle.lua",
+        include_str!("lua/meth/circle.lua"),
+    ),
+    (
+        "src/lua/meth/segment2.lua",
+        include_str!("lua/meth/segment2.lua"),
+    ),
+    (
+        "src/lua/meth/segment3.lua",
+        include_str!("lua/meth/segment3.lua"),
+    ),
+];
+
+#[cfg(not(feature = "luau"))]
+const VEC3_SOURCE: (&str, &str) = ("src/lua/meth/vec3.lua", include_str!("lua/meth/vec3.lua"));
+/// Luau has a native vector type, Vec3 maps to it instead of a table.
+#[cfg(feature = "luau")]
+const VEC3_SOURCE: (&str, &str) = (
+    "src/lua/meth/vec3_luau.lua",
+    include_str!("lua/meth/vec3_luau.lua"),
+);
+
+/// Helpers shared by the type files, `require`d as `meth.definitions`.
+pub const DEFINITIONS_SOURCE: (&str, &str) =
+    ("src/lua/meth/mod.lua", include_str!("lua/meth/mod.lua"));
+
+/// Lua files of lopa-test, `require`d as `lopa-test` and `lopa-test.format`, ...
+pub const LOPA_TEST_SOURCES: &[(&str, &str)] = &[
+    ("src/lua/lopa-test.lua", include_str!("lua/lopa-test.lua")),
+    (
+        "src/lua/lopa-test/format.lua",
+        include_str!("lua/lopa-test/format.lua"),
+    ),
+    (
+        "src/lua/lopa-test/inspect.lua",
+        include_str!("lua/lopa-test/inspect.lua"),
+    ),
+    (
+        "src/lua/lopa-test/property.lua",
+        include_str!("lua/lopa-test/property.lua"),
+    ),
+    (
+        "src/lua/lopa-test/snapshot.lua",
+        include_str!("lua/lopa-test/snapshot.lua"),
+    ),
+    (
+        "src/lua/lopa-test/spy.lua",
+        include_str!("lua/lopa-test/spy.lua"),
+    ),
+];
+
+/// Integer division and bitwise metamethods of the ivec types, Lua 5.4 only.
+#[cfg(feature = "lua54")]
+pub const INTEGER_SOURCE: (&str, &str) = (
+    "src/lua/meth/integer.lua",
+    include_str!("lua/meth/integer.lua"),
+);
+
+/// Every embedded Lua file of this backend.
+fn embedded() -> impl Iterator<Item = &'static (&'static str, &'static str)> {
+    let files = TYPE_SOURCES
+        .iter()
+        .chain(std::iter::once(&DEFINITIONS_SOURCE))
+        .chain(LOPA_TEST_SOURCES);
+    #[cfg(feature = "lua54")]
+    let files = files.chain(std::iter::once(&INTEGER_SOURCE));
+    files
+}
+
+/// Embedded source of a meth Lua file by its path in this repository.
+pub fn embedded_source(path: &str) -> Option<&'static str> {
+    embedded()
+        .find(|(name, _)| *name == path)
+        .map(|(_, source)| *source)
+}
+
+/// Path of the embedded file behind the module `name`: `meth.definitions`, `meth.<type>`,
+/// `lopa-test` and `lopa-test.<file>`. The searcher `apply` installs serves all but the types.
+pub fn module_path(name: &str) -> Option<&'static str> {
+    let path = match name {
+        "meth.definitions" => DEFINITIONS_SOURCE.0.to_owned(),
+        "lopa-test" => "src/lua/lopa-test.lua".to_owned(),
+        _ => match (name.strip_prefix("meth."), name.strip_prefix("lopa-test.")) {
+            // by type name, so that `meth.vec3` also finds backend specific files
+            (Some(ty), _) => {
+                return embedded()
+                    .map(|(path, _)| *path)
+                    .find(|path| path.starts_with("src/lua/meth/") && type_name(path) == ty);
+            }
+            (_, Some(file)) => format!("src/lua/lopa-test/{file}.lua"),
+            _ => return None,
+        },
+    };
+    embedded()
+        .map(|(embedded, _)| *embedded)
+        .find(|embedded| *embedded == path)
+}
+
+/// How `apply_with` installs meth into a Lua state.
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Defines the constructors (`vec2`, `ivec3`, `mat3`, ...) as globals.
+    pub globals: bool,
+    /// Type files to load by name (`"vec3"`, `"mat4"`, ...), all of them if `None`. Methods
+    /// converting to a type that was left out fail when called.
+    pub types: Option<Vec<String>>,
+    /// Name under which `require` returns one table with every installed constructor, the
+    /// definitions tables (`Mat2.IDENTITY`, ...) and the helpers of `src/lua/meth/mod.lua`.
+    pub module_name: Option<String>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            globals: true,
+            types: None,
+            module_name: Some("meth".to_owned()),
+        }
+    }
+}
+
+impl Options {
+    fn installs(&self, name: &str) -> bool {
+        self.types
+            .as_ref()
+            .is_none_or(|types| types.iter().any(|ty| ty == name))
+    }
+}
+
+/// Name of a type file (`vec3`) from its path, backend specific files end in `_luau`.
+fn type_name(path: &str) -> &str {
+    let name = path
+        .rsplit('/')
+        .next()
+        .and_then(|file| file.strip_suffix(".lua"))
+        .unwrap_or(path);
+    name.strip_suffix("_luau").unwrap_or(name)
+}
+
+/// Name of the definitions table a type file returns, `Mat3` for `mat3`, `IRect` for `irect`.
+fn definitions_name(name: &str) -> String {
+    match name.strip_prefix('i') {
+        Some(rest) => format!("I{}", definitions_name(rest)),
+        None => name[..1].to_uppercase() + &name[1..],
+    }
+}
+
+pub fn apply(lua: &mut mlua::Lua) -> mlua::Result<()> {
+    apply_with(lua, Options::default())
+}
+
+pub fn apply_with(lua: &mlua::Lua, options: Options) -> mlua::Result<()> {
+    apply_sources(lua, &options, None)
+}
+
+/// Like `apply`, but reads every embedded file below `root` when it exists there, so that edits
+/// to the Lua sources show up without recompiling.
+pub fn apply_from_dir(lua: &mut mlua::Lua, root: &Path) -> mlua::Result<()> {
+    apply_sources(lua, &Options::default(), Some(root))
+}
+
+/// Source of the embedded file `path`, read from below `root` instead if it exists there.
+fn load_source(root: Option<&Path>, path: &str) -> mlua::Result<Cow<'static, str>> {
+    let embedded = embedded_source(path)
+        .ok_or_else(|| lua_error::lua_error!("`{path}` is not an embedded file"))?;
+    let Some(root) = root else {
+        return Ok(embedded.into());
+    };
+    match std::fs::read_to_string(root.join(path)) {
+        Ok(source) => Ok(source.into()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(embedded.into()),
+        Err(err) => Err(lua_error::lua_error!("could not read `{path}`: {err}")),
+    }
+}
+
+/// Adds a `package.searchers` (`package.loaders` on LuaJIT) entry right after the preload one,
+/// serving the embedded modules regardless of `package.path` and the working directory. The type
+/// modules are never loaded by it, `apply_sources` stores them in `package.loaded`. Applying meth
+/// again replaces the entry of the earlier call.
+fn install_searcher(lua: &mlua::Lua, root: Option<&Path>) -> mlua::Result<()> {
+    let root = root.map(Path::to_path_buf);
+    let searcher = lua.create_function(move |lua, name: String| {
+        let Some(path) = module_path(&name) else {
+            return format!("\n\tno embedded module '{name}'").into_lua_multi(lua);
+        };
+        if TYPE_SOURCES.iter().any(|(type_path, _)| *type_path == path) {
+            return format!("\n\tmeth type module '{name}' is not installed").into_lua_multi(lua);
+        }
+        let loader = lua
+            .load(load_source(root.as_deref(), path)?.as_ref())
+            .set_name(format!("@{path}"))
+            .into_function()?;
+        (loader, path).into_lua_multi(lua)
+    })?;
+    lua.load(
+        r"
+local searcher = ...
+local meth = _G['__inner'].meth
+local searchers = package.searchers or package.loaders
+local previous = meth.searcher
+meth.searcher = searcher
+for i, existing in ipairs(searchers) do
+	if existing == previous then
+		searchers[i] = searcher
+		return
+	end
+end
+table.insert(searchers, 2, searcher)
+        ",
+    )
+    .set_name("=meth searcher")
+    .call(searcher)
+}
+
+fn apply_sources(lua: &mlua::Lua, options: &Options, root: Option<&Path>) -> mlua::Result<()> {
+    lua.load(
+        r"
+local inner = _G['__inner']
+local inner = inner ~= nil and inner or {}
+inner.meth = { searcher = inner.meth ~= nil and inner.meth.searcher or nil }
+_G['__inner'] = inner
+        ",
+    )
+    .exec()?;
+    install_searcher(lua, root)?;
+    #[cfg(feature = "luau")]
+    crate::luau::install_vector_constructor(lua)?;
+
+    // without globals the type files share a private environment, so that they still see each
+    // other's constructors
+    let env = if options.globals {
+        lua.globals()
+    } else {
+        let env = lua.create_table()?;
+        let metatable = lua.create_table()?;
+        metatable.set("__index", lua.globals())?;
+        env.set_metatable(Some(metatable));
+        env
+    };
+
+    let module = lua.create_table()?;
+    let helpers: mlua::Table = lua
+        .globals()
+        .get::<mlua::Function>("require")?
+        .call("meth.definitions")?;
+    for pair in helpers.pairs::<mlua::Value, mlua::Value>() {
+        let (key, value) = pair?;
+        module.set(key, value)?;
+    }
+    module.set(
+        "uniform_block",
+        lua.create_function(crate::layout::uniform_block)?,
+    )?;
+    module.set("curves", crate::curves::lua_module(lua)?)?;
+    module.set("noise", crate::noise::lua_module(lua)?)?;
+
+    let loaded = mlua::ErrorContext::with_context(
+        lua.globals()
+            .get::<mlua::Table>("package")
+            .and_then(|package| package.get::<mlua::Table>("loaded")),
+        |_| "could not get package.loaded table",
+    )?;
+    for (path, _) in TYPE_SOURCES {
+        let name = type_name(path);
+        if !options.installs(name) {
+            continue;
+        }
+        let definitions: mlua::Value = lua
+            .load(load_source(root, path)?.as_ref())
+            .set_name(format!("@{path}"))
+            .set_environment(env.clone())
+            .eval()?;
+        let constructor = env.raw_get::<mlua::Value>(name)?;
+        // `require("meth.<type>")` returns the definitions and the constructor as `new`
+        let type_module = lua.create_table()?;
+        if let mlua::Value::Table(definitions) = &definitions {
+            for pair in definitions.pairs::<mlua::Value, mlua::Value>() {
+                let (key, value) = pair?;
+                type_module.set(key, value)?;
+            }
+            module.set(definitions_name(name), definitions)?;
+        }
+        type_module.set("new", constructor.clone())?;
+        loaded.set(format!("meth.{name}"), type_module)?;
+        module.set(name, constructor)?;
+    }
+    #[cfg(feature = "luau")]
+    crate::luau::install_vector_metatable(lua)?;
+    #[cfg(feature = "lua54")]
+    {
+        let (path, _) = INTEGER_SOURCE;
+        lua.load(load_source(root, path)?.as_ref())
+            .set_name(format!("@{path}"))
+            .exec()?;
+    }
+
+    if let Some(module_name) = &options.module_name {
+        let package =
+            mlua::ErrorContext::with_context(lua.globals().get::<mlua::Table>("package"), |_| {
+                "could not get package table"
+            })?;
+        // Luau's `require` has no preload table, the module is stored as already loaded there
+        match package.get::<Option<mlua::Table>>("preload")? {
+            Some(preload) => preload.set(
+                module_name.as_str(),
+                lua.create_function(move |_, ()| Ok(module.clone()))?,
+            )?,
+            None => mlua::ErrorContext::with_context(package.get::<mlua::Table>("loaded"), |_| {
+                "could not get package.loaded table"
+            })?
+            .set(module_name.as_str(), module)?,
+        }
+    }
+
+    // lua.load(include_str!("lua/meth/test.lua")).exec()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn module_paths() {
+        assert_eq!(module_path("meth.vec3"), Some("src/lua/meth/vec3.lua"));
+        assert_eq!(
+            module_path("meth.definitions"),
+            Some("src/lua/meth/mod.lua")
+        );
+        assert_eq!(module_path("lopa-test"), Some("src/lua/lopa-test.lua"));
+        assert_eq!(
+            module_path("lopa-test.format"),
+            Some("src/lua/lopa-test/format.lua")
+        );
+        assert_eq!(module_path("meth.missing"), None);
+        assert_eq!(module_path("src.lua.meth"), None);
+    }
+
+    #[test]
+    fn apply_without_globals() {
+        let lua = mlua::Lua::new();
+        apply_with(
+            &lua,
+            Options {
+                globals: false,
+                types: Some(vec!["vec3".to_owned()]),
+                module_name: Some("geometry".to_owned()),
+            },
+        )
+        .unwrap();
+
+        let vec3: Option<mlua::Function> = lua.globals().get("vec3").unwrap();
+        assert!(vec3.is_none());
+        let length: f32 = lua
+            .load("return require('geometry').vec3(3, 4, 0):length()")
+            .eval()
+            .unwrap();
+        assert_eq!(length, 5.0);
+        let vec2: Option<mlua::Function> =
+            lua.load("return require('geometry').vec2").eval().unwrap();
+        assert!(vec2.is_none());
+
+        let length: f32 = lua
+            .load("return require('meth.vec3').new(3, 4, 0):length()")
+            .eval()
+            .unwrap();
+        assert_eq!(length, 5.0);
+        let vec3: Option<mlua::Function> = lua.globals().get("vec3").unwrap();
+        assert!(vec3.is_none());
+        assert!(lua.load("return require('meth.vec2')").exec().is_err());
+    }
+
+    #[test]
+    fn searcher_is_installed_once() {
+        let mut lua = mlua::Lua::new();
+        let count = |lua: &mlua::Lua| -> usize {
+            lua.load("return #(package.searchers or package.loaders)")
+                .eval()
+                .unwrap()
+        };
+        let before = count(&lua);
+        apply(&mut lua).unwrap();
+        apply(&mut lua).unwrap();
+        assert_eq!(count(&lua), before + 1);
+        let loaded: bool = lua
+            .load("return require('lopa-test.format') ~= nil")
+            .eval()
+            .unwrap();
+        assert!(loaded);
+    }
+
+    #[test]
+    fn type_modules_reuse_the_loaded_types() {
+        let lua = mlua::Lua::new();
+        apply_with(&lua, Options::default()).unwrap();
+        let equal: bool = lua
+            .load("return vec3(1, 2, 3) == require('meth.vec3').new(1, 2, 3)")
+            .eval()
+            .unwrap();
+        assert!(equal);
+        let identity: bool = lua
+            .load("return require('meth.mat2').IDENTITY == require('meth').Mat2.IDENTITY")
+            .eval()
+            .unwrap();
+        assert!(identity);
+    }
+}
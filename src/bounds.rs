@@ -0,0 +1,205 @@
+use crate::{
+    vec2::{IVec2, Vec2},
+    vec3::Vec3,
+};
+
+macro_rules! bounds {
+    ($name:ident, $vec:ident, $scalar:ty, $two:literal, $metatable:literal, [$($field:ident),+]) => {
+        /// Axis-aligned bounds from `min` to `max`, the edges are part of them.
+        #[derive(Default, Debug, PartialEq, Clone, Copy)]
+        #[repr(C)]
+        pub struct $name {
+            pub min: $vec,
+            pub max: $vec,
+        }
+
+        impl $name {
+            pub const fn from_min_max(min: $vec, max: $vec) -> Self {
+                Self { min, max }
+            }
+            pub fn from_center_size(center: $vec, size: $vec) -> Self {
+                let min = center - size / $two;
+                Self::from_min_max(min, min + size)
+            }
+            pub fn center(self) -> $vec {
+                (self.min + self.max) / $two
+            }
+            pub fn size(self) -> $vec {
+                self.max - self.min
+            }
+            pub fn contains(self, point: $vec) -> bool {
+                $(self.min.$field <= point.$field && point.$field <= self.max.$field)&&+
+            }
+            pub fn intersects(self, other: Self) -> bool {
+                $(self.min.$field <= other.max.$field && other.min.$field <= self.max.$field)&&+
+            }
+            /// `None` when they don't intersect.
+            pub fn intersection(self, other: Self) -> Option<Self> {
+                self.intersects(other).then(|| Self {
+                    min: $vec { $($field: self.min.$field.max(other.min.$field)),+ },
+                    max: $vec { $($field: self.max.$field.min(other.max.$field)),+ },
+                })
+            }
+            /// The smallest bounds containing both.
+            pub fn union(self, other: Self) -> Self {
+                Self {
+                    min: $vec { $($field: self.min.$field.min(other.min.$field)),+ },
+                    max: $vec { $($field: self.max.$field.max(other.max.$field)),+ },
+                }
+            }
+            /// Moves every side outwards by `amount`, or inwards for negative values.
+            pub fn expand(self, amount: $scalar) -> Self {
+                Self::from_min_max(self.min - amount, self.max + amount)
+            }
+            /// The closest point inside.
+            pub fn clamp_point(self, point: $vec) -> $vec {
+                $vec { $($field: point.$field.max(self.min.$field).min(self.max.$field)),+ }
+            }
+        }
+
+        #[cfg(feature = "lua")]
+        impl mlua::FromLua for $name {
+            fn from_lua(value: mlua::Value, _lua: &mlua::Lua) -> mlua::Result<Self> {
+                let table = value.as_table().ok_or_else(|| {
+                    lua_error::lua_error!(
+                        "`{}` should be a table, got `{}` instead",
+                        stringify!($name),
+                        value.type_name()
+                    )
+                })?;
+                Ok($name {
+                    min: mlua::ErrorContext::with_context(table.raw_get(1i32), |_| {
+                        concat!("could not get `", stringify!($name), "`s field `min`")
+                    })?,
+                    max: mlua::ErrorContext::with_context(table.raw_get(2i32), |_| {
+                        concat!("could not get `", stringify!($name), "`s field `max`")
+                    })?,
+                })
+            }
+        }
+
+        #[cfg(feature = "lua")]
+        impl mlua::IntoLua for $name {
+            fn into_lua(self, lua: &mlua::Lua) -> mlua::Result<mlua::Value> {
+                let table = lua.create_table()?;
+                let inner_table = lua
+                    .globals()
+                    .get::<Option<mlua::Table>>("__inner")?
+                    .ok_or_else(|| lua_error::lua_error!("could not get __inner"))?;
+                let meth_table = mlua::ErrorContext::with_context(
+                    inner_table.get::<mlua::Table>("meth"),
+                    |_| "could not get __inner.meth table",
+                )?;
+                let metatable = meth_table
+                    .get::<Option<mlua::Table>>($metatable)?
+                    .ok_or_else(|| lua_error::lua_error!("could not get metatable {}", $metatable))?;
+                table.set_metatable(Some(metatable));
+                mlua::ErrorContext::with_context(table.raw_set(1i32, self.min), |_| {
+                    concat!("could not set `", stringify!($name), "`s field `min`")
+                })?;
+                mlua::ErrorContext::with_context(table.raw_set(2i32, self.max), |_| {
+                    concat!("could not set `", stringify!($name), "`s field `max`")
+                })?;
+                Ok(mlua::Value::Table(table))
+            }
+        }
+    };
+}
+
+bounds!(Rect, Vec2, f32, 2.0, "rect_metatable", [x, y]);
+bounds!(IRect, IVec2, i32, 2, "irect_metatable", [x, y]);
+bounds!(Aabb3, Vec3, f32, 2.0, "aabb3_metatable", [x, y, z]);
+
+impl Rect {
+    pub fn area(self) -> f32 {
+        let size = self.size();
+        size.x * size.y
+    }
+}
+
+impl IRect {
+    pub fn area(self) -> i32 {
+        let size = self.size();
+        size.x * size.y
+    }
+}
+
+impl Aabb3 {
+    /// The surface area, see [`Self::volume`] for the space inside.
+    pub fn area(self) -> f32 {
+        let size = self.size();
+        2.0 * (size.x * size.y + size.y * size.z + size.z * size.x)
+    }
+    pub fn volume(self) -> f32 {
+        let size = self.size();
+        size.x * size.y * size.z
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn construction() {
+        let rect = Rect::from_center_size(Vec2::new(1., 1.), Vec2::new(2., 4.));
+        assert_eq!(
+            rect,
+            Rect::from_min_max(Vec2::new(0., -1.), Vec2::new(2., 3.))
+        );
+        assert_eq!(rect.center(), Vec2::new(1., 1.));
+        assert_eq!(rect.area(), 8.);
+
+        let rect = IRect::from_center_size(IVec2::new(0, 0), IVec2::new(3, 4));
+        assert_eq!(
+            rect,
+            IRect::from_min_max(IVec2::new(-1, -2), IVec2::new(2, 2))
+        );
+        assert_eq!(rect.size(), IVec2::new(3, 4));
+        assert_eq!(rect.area(), 12);
+
+        let aabb = Aabb3::from_min_max(Vec3::splat(0.), Vec3::new(1., 2., 3.));
+        assert_eq!(aabb.area(), 22.);
+        assert_eq!(aabb.volume(), 6.);
+    }
+
+    #[test]
+    fn contains_edges() {
+        let rect = IRect::from_min_max(IVec2::new(0, 0), IVec2::new(2, 2));
+        assert!(rect.contains(IVec2::new(0, 2)));
+        assert!(!rect.contains(IVec2::new(3, 1)));
+        let aabb = Aabb3::from_min_max(Vec3::splat(0.), Vec3::splat(1.));
+        assert!(aabb.contains(Vec3::new(0.5, 1., 0.)));
+        assert!(!aabb.contains(Vec3::new(0.5, 1., -0.1)));
+    }
+
+    #[test]
+    fn intersection_and_union() {
+        let a = Rect::from_min_max(Vec2::new(0., 0.), Vec2::new(2., 2.));
+        let b = Rect::from_min_max(Vec2::new(1., -1.), Vec2::new(3., 1.));
+        let c = Rect::from_min_max(Vec2::new(5., 5.), Vec2::new(6., 6.));
+        assert!(a.intersects(b));
+        assert!(!a.intersects(c));
+        assert_eq!(
+            a.intersection(b),
+            Some(Rect::from_min_max(Vec2::new(1., 0.), Vec2::new(2., 1.)))
+        );
+        assert_eq!(a.intersection(c), None);
+        assert_eq!(
+            a.union(c),
+            Rect::from_min_max(Vec2::new(0., 0.), Vec2::new(6., 6.))
+        );
+    }
+
+    #[test]
+    fn expand_and_clamp() {
+        let aabb = Aabb3::from_min_max(Vec3::splat(0.), Vec3::splat(1.)).expand(1.);
+        assert_eq!(aabb, Aabb3::from_min_max(Vec3::splat(-1.), Vec3::splat(2.)));
+        assert_eq!(
+            aabb.clamp_point(Vec3::new(3., 0.5, -4.)),
+            Vec3::new(2., 0.5, -1.)
+        );
+        let rect = IRect::from_min_max(IVec2::new(0, 0), IVec2::new(4, 4)).expand(-1);
+        assert_eq!(rect.clamp_point(IVec2::new(0, 5)), IVec2::new(1, 3));
+    }
+}
@@ -0,0 +1,48 @@
+//! [`bytemuck`](https://docs.rs/bytemuck) casts, for vertex and uniform buffers.
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::{
+    bounds::{Aabb3, IRect, Rect},
+    geometry::{Circle, Plane, Ray2, Ray3, Segment2, Segment3, Sphere},
+    mat2::Mat2,
+    mat3::Mat3,
+    mat4::Mat4,
+    vec2::{IVec2, UVec2, Vec2},
+    vec3::{IVec3, UVec3, Vec3},
+    vec4::{IVec4, UVec4, Vec4},
+};
+
+macro_rules! impl_pod {
+    ($($name:ident),+) => {
+        $(
+            // SAFETY: `#[repr(C)]` structs of only `f32`, `i32` or `u32` fields, or of such
+            // vectors, have no padding and every bit pattern is valid.
+            unsafe impl Zeroable for $name {}
+            unsafe impl Pod for $name {}
+        )+
+    };
+}
+
+impl_pod!(
+    UVec2, IVec2, Vec2, UVec3, IVec3, Vec3, UVec4, IVec4, Vec4, Mat2, Mat3, Mat4, Rect, IRect,
+    Aabb3, Ray2, Ray3, Plane, Sphere, Circle, Segment2, Segment3
+);
+
+#[cfg(test)]
+mod test {
+    use crate::{mat4::Mat4, vec3::Vec3};
+
+    #[test]
+    fn casts() {
+        let vertices = [Vec3::new(1., 2., 3.), Vec3::new(4., 5., 6.)];
+        let floats: &[f32] = bytemuck::cast_slice(&vertices);
+        assert_eq!(floats, [1., 2., 3., 4., 5., 6.]);
+        assert_eq!(bytemuck::bytes_of(&vertices[0]).len(), 12);
+
+        let matrix = Mat4::from_cols_array(&core::array::from_fn(|index| index as f32));
+        let floats: [f32; 16] = bytemuck::cast(matrix);
+        assert_eq!(floats, matrix.to_cols_array());
+        assert_eq!(<Mat4 as bytemuck::Zeroable>::zeroed(), Mat4::splat(0.));
+    }
+}
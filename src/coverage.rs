@@ -0,0 +1,238 @@
+use std::{collections::BTreeMap, fmt::Write as _, io, path::Path};
+
+/// Line hits of every Lua file loaded from a named chunk (`@path`), keyed by path.
+#[derive(Debug, Default, Clone)]
+pub struct Coverage {
+    hits: BTreeMap<String, BTreeMap<usize, u64>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileCoverage {
+    pub path: String,
+    /// Hit count of every executable line.
+    pub lines: BTreeMap<usize, u64>,
+}
+
+impl FileCoverage {
+    pub fn lines_found(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn lines_hit(&self) -> usize {
+        self.lines.values().filter(|hits| **hits > 0).count()
+    }
+}
+
+impl Coverage {
+    pub fn record(&mut self, path: &str, line: usize) {
+        *self
+            .hits
+            .entry(path.to_owned())
+            .or_default()
+            .entry(line)
+            .or_default() += 1;
+    }
+
+    /// Hit counts joined with the executable lines of each file's source. Sources are looked up
+    /// in the embedded meth files first, then on disk.
+    pub fn files(&self) -> Vec<FileCoverage> {
+        self.hits
+            .iter()
+            .map(|(path, hits)| {
+                let source = meth::embedded_source(path)
+                    .map(str::to_owned)
+                    .or_else(|| std::fs::read_to_string(path).ok())
+                    .unwrap_or_default();
+                let mut lines: BTreeMap<usize, u64> = executable_lines(&source)
+                    .into_iter()
+                    .map(|line| (line, 0))
+                    .collect();
+                lines.extend(hits);
+                FileCoverage {
+                    path: path.clone(),
+                    lines,
+                }
+            })
+            .collect()
+    }
+
+    pub fn write_lcov(&self, path: &Path) -> io::Result<()> {
+        let mut out = String::new();
+        for file in self.files() {
+            let _ = writeln!(out, "TN:");
+            let _ = writeln!(out, "SF:{}", file.path);
+            for (line, hits) in &file.lines {
+                let _ = writeln!(out, "DA:{line},{hits}");
+            }
+            let _ = writeln!(out, "LF:{}", file.lines_found());
+            let _ = writeln!(out, "LH:{}", file.lines_hit());
+            let _ = writeln!(out, "end_of_record");
+        }
+        std::fs::write(path, out)
+    }
+
+    pub fn summary(&self) -> String {
+        let files = self.files();
+        let width = files
+            .iter()
+            .map(|file| file.path.len())
+            .max()
+            .unwrap_or(0)
+            .max("total".len());
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{:<width$}  {:>6}  {:>6}  {:>7}",
+            "file", "lines", "hit", "cover"
+        );
+        let (mut found, mut hit) = (0, 0);
+        for file in &files {
+            found += file.lines_found();
+            hit += file.lines_hit();
+            let _ = writeln!(
+                out,
+                "{:<width$}  {:>6}  {:>6}  {:>6.1}%",
+                file.path,
+                file.lines_found(),
+                file.lines_hit(),
+                percent(file.lines_hit(), file.lines_found())
+            );
+        }
+        let _ = write!(
+            out,
+            "{:<width$}  {:>6}  {:>6}  {:>6.1}%",
+            "total",
+            found,
+            hit,
+            percent(hit, found)
+        );
+        out
+    }
+}
+
+fn percent(hit: usize, found: usize) -> f64 {
+    if found == 0 {
+        100.0
+    } else {
+        hit as f64 * 100.0 / found as f64
+    }
+}
+
+/// Lines that look like they contain a statement. Lua has no API for the active lines of a chunk
+/// that was not run, so blank lines, comments and lone block terminators are skipped instead, as
+/// well as lines inside block comments and multi-line strings.
+fn executable_lines(source: &str) -> Vec<usize> {
+    const TERMINATORS: &[&str] = &[
+        "end", "end,", "end)", "end),", "else", "do", "then", "repeat", "}", "},", "})", ")", "),",
+    ];
+    let mut open = None;
+    let mut lines = vec![];
+    for (i, line) in source.lines().enumerate() {
+        // the rest of a multi-line string belongs to the statement on the line it started
+        let in_string = matches!(open, Some(LongBracket { comment: false, .. }));
+        let (next, has_code) = scan_line(line, open);
+        open = next;
+        if !in_string && has_code && !TERMINATORS.contains(&line.trim()) {
+            lines.push(i + 1);
+        }
+    }
+    lines
+}
+
+/// A block comment or multi-line string spanning several lines.
+#[derive(Debug, Clone, Copy)]
+struct LongBracket {
+    /// Number of `=` between the brackets, `[==[` has level 2.
+    level: usize,
+    comment: bool,
+}
+
+/// Level of the long bracket (`[[`, `[==[`, ...) of a string or comment at `bytes[i]`, `bracket`
+/// is `[` for opening and `]` for closing ones.
+fn long_bracket(bytes: &[u8], i: usize, bracket: u8) -> Option<usize> {
+    if bytes.get(i) != Some(&bracket) {
+        return None;
+    }
+    let level = bytes[i + 1..]
+        .iter()
+        .take_while(|&&byte| byte == b'=')
+        .count();
+    (bytes.get(i + 1 + level) == Some(&bracket)).then_some(level)
+}
+
+/// The long bracket still open at the end of `line`, given the one open at its start, and whether
+/// the line has anything but whitespace outside of comments.
+fn scan_line(line: &str, mut open: Option<LongBracket>) -> (Option<LongBracket>, bool) {
+    let bytes = line.as_bytes();
+    let mut has_code = false;
+    let mut quote = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        if let Some(LongBracket { level, comment }) = open {
+            if long_bracket(bytes, i, b']') == Some(level) {
+                open = None;
+                has_code |= !comment;
+                i += level + 2;
+                continue;
+            }
+            has_code |= !comment;
+        } else if let Some(quote_byte) = quote {
+            if bytes[i] == b'\\' {
+                i += 2;
+                continue;
+            }
+            if bytes[i] == quote_byte {
+                quote = None;
+            }
+        } else if bytes[i..].starts_with(b"--") {
+            // a line comment hides the rest of the line
+            let Some(level) = long_bracket(bytes, i + 2, b'[') else {
+                break;
+            };
+            open = Some(LongBracket {
+                level,
+                comment: true,
+            });
+            i += level + 4;
+            continue;
+        } else if bytes[i] == b'"' || bytes[i] == b'\'' {
+            has_code = true;
+            quote = Some(bytes[i]);
+        } else if let Some(level) = long_bracket(bytes, i, b'[') {
+            has_code = true;
+            open = Some(LongBracket {
+                level,
+                comment: false,
+            });
+            i += level + 2;
+            continue;
+        } else {
+            has_code |= !bytes[i].is_ascii_whitespace();
+        }
+        i += 1;
+    }
+    (open, has_code)
+}
+
+#[cfg(test)]
+mod test {
+    use super::executable_lines;
+
+    #[test]
+    fn long_brackets_are_not_executable() {
+        let source = "\
+local a = 1
+--[[
+local b = 2
+]]
+local s = [==[
+local c = 3
+]==]
+print(a, s) -- [[ not a block comment
+--[=[ one line ]=] local d = 4
+return \"--[[\"
+";
+        assert_eq!(executable_lines(source), vec![1, 5, 8, 9, 10]);
+    }
+}
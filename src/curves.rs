@@ -0,0 +1,697 @@
+//! Bezier, Hermite and Catmull-Rom curves over [`Vec2`] and [`Vec3`].
+//!
+//! Every curve is a cubic polynomial of `t` in `0..=1` and implements [`Curve`] through its
+//! [`CubicBezier`] form. [`ArcLength`] maps distances along a curve back to `t`, for moving along
+//! it at a constant speed.
+
+use core::ops::{Add, Mul, Sub};
+
+use crate::{
+    bounds::{Aabb3, Rect},
+    math,
+    vec2::Vec2,
+    vec3::Vec3,
+};
+
+/// Sections of a curve that [`ArcLength`] measures separately.
+const SEGMENTS: usize = 16;
+/// Nodes in `-1..=1` and weights of the 5 point Gauss-Legendre quadrature.
+const GAUSS_LEGENDRE: [(f32, f32); 5] = [
+    (0., 0.568_888_9),
+    (-0.538_469_3, 0.478_628_67),
+    (0.538_469_3, 0.478_628_67),
+    (-0.906_179_85, 0.236_926_88),
+    (0.906_179_85, 0.236_926_88),
+];
+const EPSILON: f32 = 1e-6;
+
+/// The vectors curves are made of.
+pub trait Point: Copy + Add<Output = Self> + Sub<Output = Self> + Mul<f32, Output = Self> {
+    /// [`Rect`] or [`Aabb3`].
+    type Bounds;
+    const DIMENSIONS: usize;
+    fn component(self, index: usize) -> f32;
+    fn length(self) -> f32;
+    fn min(self, rhs: Self) -> Self;
+    fn max(self, rhs: Self) -> Self;
+    fn bounds(min: Self, max: Self) -> Self::Bounds;
+}
+
+macro_rules! impl_point {
+    ($name:ident, $bounds:ident, $dimensions:literal, [$($index:literal => $field:ident),+]) => {
+        impl Point for $name {
+            type Bounds = $bounds;
+            const DIMENSIONS: usize = $dimensions;
+            fn component(self, index: usize) -> f32 {
+                match index {
+                    $($index => self.$field,)+
+                    _ => panic!("{} has no component {index}", stringify!($name)),
+                }
+            }
+            fn length(self) -> f32 {
+                $name::length(self)
+            }
+            fn min(self, rhs: Self) -> Self {
+                $name::min(self, rhs)
+            }
+            fn max(self, rhs: Self) -> Self {
+                $name::max(self, rhs)
+            }
+            fn bounds(min: Self, max: Self) -> $bounds {
+                $bounds::from_min_max(min, max)
+            }
+        }
+    };
+}
+
+impl_point!(Vec2, Rect, 2, [0 => x, 1 => y]);
+impl_point!(Vec3, Aabb3, 3, [0 => x, 1 => y, 2 => z]);
+
+pub trait Curve: Copy {
+    type Point: Point;
+    /// What [`Curve::split`] returns.
+    type Split: Curve<Point = Self::Point>;
+
+    /// The same curve as a cubic Bezier.
+    fn to_cubic(self) -> CubicBezier<Self::Point>;
+    /// The parts before and after `t`, each going over `0..=1` again.
+    fn split(self, t: f32) -> (Self::Split, Self::Split);
+
+    fn position(self, t: f32) -> Self::Point {
+        self.to_cubic().position(t)
+    }
+    /// The velocity at `t`.
+    fn derivative(self, t: f32) -> Self::Point {
+        self.to_cubic().derivative(t)
+    }
+    fn second_derivative(self, t: f32) -> Self::Point {
+        self.to_cubic().second_derivative(t)
+    }
+    /// The tight bounds of the curve, not just of its control points.
+    fn bounds(self) -> <Self::Point as Point>::Bounds {
+        self.to_cubic().bounds()
+    }
+    fn arc_length(self) -> ArcLength<Self::Point> {
+        ArcLength::new(self)
+    }
+    fn length(self) -> f32 {
+        self.arc_length().length()
+    }
+    /// `count` points evenly spaced along the curve, both ends included.
+    fn sample_uniform(self, count: usize) -> impl Iterator<Item = Self::Point> {
+        self.arc_length().sample_uniform(count)
+    }
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct QuadraticBezier<P> {
+    pub p0: P,
+    pub p1: P,
+    pub p2: P,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct CubicBezier<P> {
+    pub p0: P,
+    pub p1: P,
+    pub p2: P,
+    pub p3: P,
+}
+
+/// From `p0` leaving with the tangent `m0` to `p1` arriving with `m1`.
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct Hermite<P> {
+    pub p0: P,
+    pub m0: P,
+    pub p1: P,
+    pub m1: P,
+}
+
+/// The segment from `p1` to `p2` of the spline through all four points.
+///
+/// `alpha` spaces the knots by the distances between the points raised to it, see
+/// [`Self::UNIFORM`], [`Self::CENTRIPETAL`] and [`Self::CHORDAL`]. The centripetal variant
+/// neither overshoots nor forms loops.
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct CatmullRom<P> {
+    pub p0: P,
+    pub p1: P,
+    pub p2: P,
+    pub p3: P,
+    pub alpha: f32,
+}
+
+impl<P: Point> QuadraticBezier<P> {
+    pub const fn new(p0: P, p1: P, p2: P) -> Self {
+        Self { p0, p1, p2 }
+    }
+}
+
+impl<P: Point> CubicBezier<P> {
+    pub const fn new(p0: P, p1: P, p2: P, p3: P) -> Self {
+        Self { p0, p1, p2, p3 }
+    }
+}
+
+impl<P: Point> Hermite<P> {
+    pub const fn new(p0: P, m0: P, p1: P, m1: P) -> Self {
+        Self { p0, m0, p1, m1 }
+    }
+}
+
+impl<P: Point> CatmullRom<P> {
+    pub const UNIFORM: f32 = 0.;
+    pub const CENTRIPETAL: f32 = 0.5;
+    pub const CHORDAL: f32 = 1.;
+
+    pub const fn new(p0: P, p1: P, p2: P, p3: P, alpha: f32) -> Self {
+        Self {
+            p0,
+            p1,
+            p2,
+            p3,
+            alpha,
+        }
+    }
+    pub const fn uniform(p0: P, p1: P, p2: P, p3: P) -> Self {
+        Self::new(p0, p1, p2, p3, Self::UNIFORM)
+    }
+    pub const fn centripetal(p0: P, p1: P, p2: P, p3: P) -> Self {
+        Self::new(p0, p1, p2, p3, Self::CENTRIPETAL)
+    }
+    /// The segment as a Hermite curve, with the tangents scaled to its `0..=1` range.
+    pub fn to_hermite(self) -> Hermite<P> {
+        let knot = |a: P, b: P| {
+            let interval = math::powf((b - a).length(), self.alpha);
+            // repeated points would divide by zero
+            if interval < EPSILON { 1. } else { interval }
+        };
+        let (d0, d1, d2) = (
+            knot(self.p0, self.p1),
+            knot(self.p1, self.p2),
+            knot(self.p2, self.p3),
+        );
+        let m1 = (self.p1 - self.p0) * (1. / d0) - (self.p2 - self.p0) * (1. / (d0 + d1))
+            + (self.p2 - self.p1) * (1. / d1);
+        let m2 = (self.p2 - self.p1) * (1. / d1) - (self.p3 - self.p1) * (1. / (d1 + d2))
+            + (self.p3 - self.p2) * (1. / d2);
+        Hermite::new(self.p1, m1 * d1, self.p2, m2 * d1)
+    }
+}
+
+impl<P: Point> Curve for CubicBezier<P> {
+    type Point = P;
+    type Split = Self;
+
+    fn to_cubic(self) -> CubicBezier<P> {
+        self
+    }
+    fn split(self, t: f32) -> (Self, Self) {
+        let lerp = |a: P, b: P| a + (b - a) * t;
+        let (a, b, c) = (
+            lerp(self.p0, self.p1),
+            lerp(self.p1, self.p2),
+            lerp(self.p2, self.p3),
+        );
+        let (d, e) = (lerp(a, b), lerp(b, c));
+        let middle = lerp(d, e);
+        (
+            Self::new(self.p0, a, d, middle),
+            Self::new(middle, e, c, self.p3),
+        )
+    }
+    fn position(self, t: f32) -> P {
+        let u = 1. - t;
+        self.p0 * (u * u * u)
+            + self.p1 * (3. * u * u * t)
+            + self.p2 * (3. * u * t * t)
+            + self.p3 * (t * t * t)
+    }
+    fn derivative(self, t: f32) -> P {
+        let u = 1. - t;
+        (self.p1 - self.p0) * (3. * u * u)
+            + (self.p2 - self.p1) * (6. * u * t)
+            + (self.p3 - self.p2) * (3. * t * t)
+    }
+    fn second_derivative(self, t: f32) -> P {
+        let u = 1. - t;
+        (self.p2 - self.p1 * 2. + self.p0) * (6. * u)
+            + (self.p3 - self.p2 * 2. + self.p1) * (6. * t)
+    }
+    fn bounds(self) -> P::Bounds {
+        let mut min = self.p0.min(self.p3);
+        let mut max = self.p0.max(self.p3);
+        // the derivative of each component is a quadratic, the extremes are at its roots
+        let (d0, d1, d2) = (self.p1 - self.p0, self.p2 - self.p1, self.p3 - self.p2);
+        for index in 0..P::DIMENSIONS {
+            let (d0, d1, d2) = (
+                d0.component(index),
+                d1.component(index),
+                d2.component(index),
+            );
+            let roots = quadratic_roots(d0 - 2. * d1 + d2, 2. * (d1 - d0), d0);
+            for t in roots.into_iter().flatten() {
+                if 0. < t && t < 1. {
+                    let point = self.position(t);
+                    min = min.min(point);
+                    max = max.max(point);
+                }
+            }
+        }
+        P::bounds(min, max)
+    }
+}
+
+impl<P: Point> Curve for QuadraticBezier<P> {
+    type Point = P;
+    type Split = Self;
+
+    fn to_cubic(self) -> CubicBezier<P> {
+        CubicBezier::new(
+            self.p0,
+            self.p0 + (self.p1 - self.p0) * (2. / 3.),
+            self.p2 + (self.p1 - self.p2) * (2. / 3.),
+            self.p2,
+        )
+    }
+    fn split(self, t: f32) -> (Self, Self) {
+        let lerp = |a: P, b: P| a + (b - a) * t;
+        let (a, b) = (lerp(self.p0, self.p1), lerp(self.p1, self.p2));
+        let middle = lerp(a, b);
+        (Self::new(self.p0, a, middle), Self::new(middle, b, self.p2))
+    }
+}
+
+impl<P: Point> Curve for Hermite<P> {
+    type Point = P;
+    type Split = Self;
+
+    fn to_cubic(self) -> CubicBezier<P> {
+        CubicBezier::new(
+            self.p0,
+            self.p0 + self.m0 * (1. / 3.),
+            self.p1 - self.m1 * (1. / 3.),
+            self.p1,
+        )
+    }
+    fn split(self, t: f32) -> (Self, Self) {
+        let hermite = |cubic: CubicBezier<P>| {
+            Hermite::new(
+                cubic.p0,
+                (cubic.p1 - cubic.p0) * 3.,
+                cubic.p3,
+                (cubic.p3 - cubic.p2) * 3.,
+            )
+        };
+        let (before, after) = self.to_cubic().split(t);
+        (hermite(before), hermite(after))
+    }
+}
+
+impl<P: Point> Curve for CatmullRom<P> {
+    type Point = P;
+    /// Without the outer points the parts are no Catmull-Rom segments anymore.
+    type Split = CubicBezier<P>;
+
+    fn to_cubic(self) -> CubicBezier<P> {
+        self.to_hermite().to_cubic()
+    }
+    fn split(self, t: f32) -> (CubicBezier<P>, CubicBezier<P>) {
+        self.to_cubic().split(t)
+    }
+}
+
+/// The real roots of `a * t^2 + b * t + c`, also when it is only linear.
+fn quadratic_roots(a: f32, b: f32, c: f32) -> [Option<f32>; 2] {
+    if math::abs(a) < EPSILON {
+        return [(math::abs(b) >= EPSILON).then(|| -c / b), None];
+    }
+    let discriminant = b * b - 4. * a * c;
+    if discriminant < 0. {
+        return [None, None];
+    }
+    let root = math::sqrt(discriminant);
+    [Some((-b - root) / (2. * a)), Some((-b + root) / (2. * a))]
+}
+
+/// A table of the length along a curve, mapping distances to `t`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ArcLength<P> {
+    curve: CubicBezier<P>,
+    /// The length up to the start of each segment and to the end.
+    lengths: [f32; SEGMENTS + 1],
+}
+
+impl<P: Point> ArcLength<P> {
+    pub fn new(curve: impl Curve<Point = P>) -> Self {
+        let curve = curve.to_cubic();
+        let mut lengths = [0.; SEGMENTS + 1];
+        for segment in 0..SEGMENTS {
+            let start = segment as f32 / SEGMENTS as f32;
+            let end = (segment + 1) as f32 / SEGMENTS as f32;
+            lengths[segment + 1] = lengths[segment] + integrate(curve, start, end);
+        }
+        Self { curve, lengths }
+    }
+    pub fn length(&self) -> f32 {
+        self.lengths[SEGMENTS]
+    }
+    /// The `t` at `distance` along the curve, clamped to its ends.
+    pub fn t_at(&self, distance: f32) -> f32 {
+        let distance = distance.clamp(0., self.length());
+        let segment = self
+            .lengths
+            .partition_point(|&length| length <= distance)
+            .clamp(1, SEGMENTS)
+            - 1;
+        let start = segment as f32 / SEGMENTS as f32;
+        let end = (segment + 1) as f32 / SEGMENTS as f32;
+        let (before, after) = (self.lengths[segment], self.lengths[segment + 1]);
+        if after - before < EPSILON {
+            return start;
+        }
+        // Newton's method from the linear guess, the speed is the derivative of the length
+        let mut t = start + (end - start) * (distance - before) / (after - before);
+        for _ in 0..4 {
+            let error = before + integrate(self.curve, start, t) - distance;
+            let speed = self.curve.derivative(t).length();
+            if speed < EPSILON {
+                break;
+            }
+            t = (t - error / speed).clamp(start, end);
+        }
+        t
+    }
+    pub fn position_at(&self, distance: f32) -> P {
+        self.curve.position(self.t_at(distance))
+    }
+    /// `count` points evenly spaced along the curve, both ends included.
+    pub fn sample_uniform(self, count: usize) -> impl Iterator<Item = P> {
+        let spacing = if count > 1 {
+            self.length() / (count - 1) as f32
+        } else {
+            0.
+        };
+        (0..count).map(move |index| self.position_at(spacing * index as f32))
+    }
+}
+
+/// The length of `curve` from `start` to `end`.
+fn integrate<P: Point>(curve: CubicBezier<P>, start: f32, end: f32) -> f32 {
+    let half = (end - start) / 2.;
+    let middle = start + half;
+    GAUSS_LEGENDRE
+        .iter()
+        .map(|&(node, weight)| weight * curve.derivative(middle + half * node).length())
+        .sum::<f32>()
+        * half
+}
+
+#[cfg(feature = "lua")]
+mod lua {
+    use mlua::{FromLuaMulti, IntoLua};
+
+    use super::*;
+
+    fn add_curve_methods<C, M>(methods: &mut M)
+    where
+        C: Curve + mlua::UserData + 'static,
+        C::Point: mlua::IntoLua,
+        C::Split: mlua::IntoLua,
+        CubicBezier<C::Point>: mlua::IntoLua,
+        <C::Point as Point>::Bounds: mlua::IntoLua,
+        M: mlua::UserDataMethods<C>,
+    {
+        methods.add_method("position", |_, this, t: f32| Ok(this.position(t)));
+        methods.add_method("derivative", |_, this, t: f32| Ok(this.derivative(t)));
+        methods.add_method("second_derivative", |_, this, t: f32| {
+            Ok(this.second_derivative(t))
+        });
+        methods.add_method("split", |_, this, t: f32| Ok(this.split(t)));
+        methods.add_method("bounds", |_, this, ()| Ok(this.bounds()));
+        methods.add_method("to_cubic", |_, this, ()| Ok(this.to_cubic()));
+        methods.add_method("length", |_, this, ()| Ok(this.length()));
+        methods.add_method("t_at", |_, this, distance: f32| {
+            Ok(this.arc_length().t_at(distance))
+        });
+        methods.add_method("position_at", |_, this, distance: f32| {
+            Ok(this.arc_length().position_at(distance))
+        });
+        methods.add_method("sample_uniform", |_, this, count: usize| {
+            Ok(this.sample_uniform(count).collect::<Vec<_>>())
+        });
+    }
+
+    macro_rules! impl_user_data {
+        ($($curve:ident),+) => {
+            $(
+                impl<P> mlua::UserData for $curve<P>
+                where
+                    P: Point + mlua::IntoLua + Send + 'static,
+                    P::Bounds: mlua::IntoLua,
+                {
+                    fn add_methods<M: mlua::UserDataMethods<Self>>(methods: &mut M) {
+                        add_curve_methods(methods);
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_user_data!(QuadraticBezier, CubicBezier, Hermite, CatmullRom);
+
+    /// A constructor building the `Vec3` curve when the first point is a `vec3` and the `Vec2`
+    /// one otherwise.
+    fn constructor<A2, A3, C2, C3>(
+        lua: &mlua::Lua,
+        vec2: fn(A2) -> C2,
+        vec3: fn(A3) -> C3,
+    ) -> mlua::Result<mlua::Function>
+    where
+        A2: FromLuaMulti,
+        A3: FromLuaMulti,
+        C2: IntoLua,
+        C3: IntoLua,
+    {
+        lua.create_function(move |lua, args: mlua::MultiValue| {
+            let is_vec3 = match args.iter().next() {
+                Some(mlua::Value::Table(point)) => point.len()? == 3,
+                #[cfg(feature = "luau")]
+                Some(mlua::Value::Vector(_)) => true,
+                _ => false,
+            };
+            if is_vec3 {
+                vec3(A3::from_lua_multi(args, lua)?).into_lua(lua)
+            } else {
+                vec2(A2::from_lua_multi(args, lua)?).into_lua(lua)
+            }
+        })
+    }
+
+    /// The `curves` table of the Lua module, its constructors take `vec2` or `vec3` points and
+    /// the curves return those from their methods.
+    pub(crate) fn module(lua: &mlua::Lua) -> mlua::Result<mlua::Table> {
+        let module = lua.create_table()?;
+        module.set(
+            "quadratic_bezier",
+            constructor(
+                lua,
+                |(p0, p1, p2): (Vec2, Vec2, Vec2)| QuadraticBezier::new(p0, p1, p2),
+                |(p0, p1, p2): (Vec3, Vec3, Vec3)| QuadraticBezier::new(p0, p1, p2),
+            )?,
+        )?;
+        module.set(
+            "cubic_bezier",
+            constructor(
+                lua,
+                |(p0, p1, p2, p3): (Vec2, Vec2, Vec2, Vec2)| CubicBezier::new(p0, p1, p2, p3),
+                |(p0, p1, p2, p3): (Vec3, Vec3, Vec3, Vec3)| CubicBezier::new(p0, p1, p2, p3),
+            )?,
+        )?;
+        module.set(
+            "hermite",
+            constructor(
+                lua,
+                |(p0, m0, p1, m1): (Vec2, Vec2, Vec2, Vec2)| Hermite::new(p0, m0, p1, m1),
+                |(p0, m0, p1, m1): (Vec3, Vec3, Vec3, Vec3)| Hermite::new(p0, m0, p1, m1),
+            )?,
+        )?;
+        // uniform without `alpha`
+        module.set(
+            "catmull_rom",
+            constructor(
+                lua,
+                |(p0, p1, p2, p3, alpha): (Vec2, Vec2, Vec2, Vec2, Option<f32>)| {
+                    CatmullRom::new(p0, p1, p2, p3, alpha.unwrap_or(CatmullRom::<Vec2>::UNIFORM))
+                },
+                |(p0, p1, p2, p3, alpha): (Vec3, Vec3, Vec3, Vec3, Option<f32>)| {
+                    CatmullRom::new(p0, p1, p2, p3, alpha.unwrap_or(CatmullRom::<Vec3>::UNIFORM))
+                },
+            )?,
+        )?;
+        module.set(
+            "centripetal_catmull_rom",
+            constructor(
+                lua,
+                |(p0, p1, p2, p3): (Vec2, Vec2, Vec2, Vec2)| {
+                    CatmullRom::centripetal(p0, p1, p2, p3)
+                },
+                |(p0, p1, p2, p3): (Vec3, Vec3, Vec3, Vec3)| {
+                    CatmullRom::centripetal(p0, p1, p2, p3)
+                },
+            )?,
+        )?;
+        Ok(module)
+    }
+}
+
+#[cfg(feature = "lua")]
+pub(crate) use lua::module as lua_module;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_near<P: Point + core::fmt::Debug>(a: P, b: P) {
+        assert!((a - b).length() < 1e-4, "{a:?} != {b:?}");
+    }
+
+    fn cubic() -> CubicBezier<Vec2> {
+        CubicBezier::new(
+            Vec2::new(0., 0.),
+            Vec2::new(0., 2.),
+            Vec2::new(4., 2.),
+            Vec2::new(4., 0.),
+        )
+    }
+
+    #[test]
+    fn evaluation() {
+        let curve = cubic();
+        assert_eq!(curve.position(0.), Vec2::new(0., 0.));
+        assert_eq!(curve.position(1.), Vec2::new(4., 0.));
+        assert_eq!(curve.position(0.5), Vec2::new(2., 1.5));
+        assert_eq!(curve.derivative(0.), Vec2::new(0., 6.));
+        assert_eq!(curve.derivative(0.5), Vec2::new(6., 0.));
+        let h = 1e-3;
+        let t = 0.3;
+        let difference = (curve.derivative(t + h) - curve.derivative(t - h)) * (1. / (2. * h));
+        assert!((difference - curve.second_derivative(t)).length() < 1e-2);
+    }
+
+    #[test]
+    fn conversions() {
+        let quadratic = QuadraticBezier::new(
+            Vec3::new(0., 0., 0.),
+            Vec3::new(1., 2., 0.),
+            Vec3::new(2., 0., 1.),
+        );
+        let cubic = quadratic.to_cubic();
+        for t in [0., 0.25, 0.5, 0.9] {
+            let u = 1. - t;
+            let expected =
+                quadratic.p0 * (u * u) + quadratic.p1 * (2. * u * t) + quadratic.p2 * (t * t);
+            assert_near(cubic.position(t), expected);
+        }
+
+        let hermite = Hermite::new(
+            Vec2::new(0., 0.),
+            Vec2::new(3., 0.),
+            Vec2::new(1., 1.),
+            Vec2::new(0., 3.),
+        );
+        assert_eq!(hermite.derivative(0.), Vec2::new(3., 0.));
+        assert_eq!(hermite.derivative(1.), Vec2::new(0., 3.));
+        assert_eq!(hermite.position(1.), Vec2::new(1., 1.));
+    }
+
+    #[test]
+    fn catmull_rom() {
+        let points = [
+            Vec2::new(0., 0.),
+            Vec2::new(1., 1.),
+            Vec2::new(2., 1.),
+            Vec2::new(3., 0.),
+        ];
+        let uniform = CatmullRom::uniform(points[0], points[1], points[2], points[3]);
+        assert_eq!(uniform.position(0.), points[1]);
+        assert_eq!(uniform.position(1.), points[2]);
+        assert_near(uniform.derivative(0.), (points[2] - points[0]) * 0.5);
+
+        let centripetal = CatmullRom::centripetal(points[0], points[1], points[2], points[3]);
+        assert_near(centripetal.position(0.), points[1]);
+        assert_near(centripetal.position(1.), points[2]);
+        // repeated points don't break it
+        let repeated = CatmullRom::centripetal(points[1], points[1], points[2], points[2]);
+        assert_near(repeated.position(0.5), Vec2::new(1.5, 1.));
+    }
+
+    #[test]
+    fn split() {
+        let curve = cubic();
+        let (before, after) = curve.split(0.25);
+        assert_eq!(before.p3, after.p0);
+        assert_near(before.position(0.5), curve.position(0.125));
+        assert_near(after.position(0.5), curve.position(0.625));
+
+        let hermite = Hermite::new(
+            Vec2::splat(0.),
+            Vec2::new(2., 0.),
+            Vec2::splat(1.),
+            Vec2::new(0., 2.),
+        );
+        let (before, after) = hermite.split(0.5);
+        assert_near(before.position(1.), hermite.position(0.5));
+        assert_near(after.derivative(1.), hermite.derivative(1.) * 0.5);
+
+        let quadratic = QuadraticBezier::new(Vec2::splat(0.), Vec2::new(1., 2.), Vec2::new(2., 0.));
+        let (before, _) = quadratic.split(0.5);
+        assert_eq!(before.p2, Vec2::new(1., 1.));
+    }
+
+    #[test]
+    fn bounds() {
+        let bounds = cubic().bounds();
+        assert_near(bounds.min, Vec2::new(0., 0.));
+        assert_near(bounds.max, Vec2::new(4., 1.5));
+
+        let curve = CubicBezier::new(
+            Vec3::new(0., 0., 0.),
+            Vec3::new(-1., 0., 1.),
+            Vec3::new(-1., 0., 1.),
+            Vec3::new(0., 0., 0.),
+        );
+        let bounds = curve.bounds();
+        assert_near(bounds.min, Vec3::new(-0.75, 0., 0.));
+        assert_near(bounds.max, Vec3::new(0., 0., 0.75));
+    }
+
+    #[test]
+    fn arc_length() {
+        // a straight line that is slow at its start
+        let line = CubicBezier::new(
+            Vec2::new(0., 0.),
+            Vec2::new(0., 0.),
+            Vec2::new(0., 0.),
+            Vec2::new(3., 0.),
+        );
+        let arc_length = line.arc_length();
+        assert!((arc_length.length() - 3.).abs() < 1e-4);
+        assert!((arc_length.t_at(3. / 8.) - 0.5).abs() < 1e-4);
+        let samples: Vec<_> = line.sample_uniform(4).collect();
+        for (sample, x) in samples.into_iter().zip([0., 1., 2., 3.]) {
+            assert_near(sample, Vec2::new(x, 0.));
+        }
+
+        // a quarter of a unit circle is about pi / 2 long
+        let quarter = Hermite::new(
+            Vec2::new(1., 0.),
+            Vec2::new(0., 1.65),
+            Vec2::new(0., 1.),
+            Vec2::new(-1.65, 0.),
+        );
+        assert!((quarter.length() - core::f32::consts::FRAC_PI_2).abs() < 1e-2);
+        assert_eq!(quarter.sample_uniform(1).count(), 1);
+        assert_eq!(quarter.sample_uniform(0).count(), 0);
+    }
+}
@@ -0,0 +1,305 @@
+//! GLSL-style shaping functions and easing curves for `f32` and, componentwise, the float
+//! vectors. The Lua module has the same functions, taking numbers or vectors.
+
+use core::f32::consts::PI;
+
+use crate::{math, vec2::Vec2, vec3::Vec3, vec4::Vec4};
+
+/// Easing curves mapping `0..=1` to `0..=1`, like on <https://easings.net>. `Back` and
+/// `Elastic` overshoot on the way.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Easing {
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+}
+
+impl Easing {
+    pub fn apply(self, t: f32) -> f32 {
+        const BACK: f32 = 1.70158;
+        const BACK_IN_OUT: f32 = BACK * 1.525;
+        const ELASTIC: f32 = 2. * PI / 3.;
+        const ELASTIC_IN_OUT: f32 = 2. * PI / 4.5;
+
+        fn bounce_out(t: f32) -> f32 {
+            const N: f32 = 7.5625;
+            const D: f32 = 2.75;
+            if t < 1. / D {
+                N * t * t
+            } else if t < 2. / D {
+                let t = t - 1.5 / D;
+                N * t * t + 0.75
+            } else if t < 2.5 / D {
+                let t = t - 2.25 / D;
+                N * t * t + 0.9375
+            } else {
+                let t = t - 2.625 / D;
+                N * t * t + 0.984375
+            }
+        }
+
+        match self {
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1. - (1. - t) * (1. - t),
+            Easing::QuadInOut if t < 0.5 => 2. * t * t,
+            Easing::QuadInOut => 1. - (2. - 2. * t) * (2. - 2. * t) / 2.,
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => 1. - (1. - t) * (1. - t) * (1. - t),
+            Easing::CubicInOut if t < 0.5 => 4. * t * t * t,
+            Easing::CubicInOut => 1. - (2. - 2. * t) * (2. - 2. * t) * (2. - 2. * t) / 2.,
+            // the curves don't quite reach their ends on their own
+            Easing::ElasticIn | Easing::ElasticOut | Easing::ElasticInOut if t <= 0. => 0.,
+            Easing::ElasticIn | Easing::ElasticOut | Easing::ElasticInOut if t >= 1. => 1.,
+            Easing::ElasticIn => {
+                -math::exp2(10. * t - 10.) * math::sin((10. * t - 10.75) * ELASTIC)
+            }
+            Easing::ElasticOut => math::exp2(-10. * t) * math::sin((10. * t - 0.75) * ELASTIC) + 1.,
+            Easing::ElasticInOut if t < 0.5 => {
+                -math::exp2(20. * t - 10.) * math::sin((20. * t - 11.125) * ELASTIC_IN_OUT) / 2.
+            }
+            Easing::ElasticInOut => {
+                math::exp2(-20. * t + 10.) * math::sin((20. * t - 11.125) * ELASTIC_IN_OUT) / 2.
+                    + 1.
+            }
+            Easing::BounceIn => 1. - bounce_out(1. - t),
+            Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut if t < 0.5 => (1. - bounce_out(1. - 2. * t)) / 2.,
+            Easing::BounceInOut => (1. + bounce_out(2. * t - 1.)) / 2.,
+            Easing::BackIn => (BACK + 1.) * t * t * t - BACK * t * t,
+            Easing::BackOut => {
+                let t = t - 1.;
+                1. + (BACK + 1.) * t * t * t + BACK * t * t
+            }
+            Easing::BackInOut if t < 0.5 => {
+                let t = 2. * t;
+                t * t * ((BACK_IN_OUT + 1.) * t - BACK_IN_OUT) / 2.
+            }
+            Easing::BackInOut => {
+                let t = 2. * t - 2.;
+                (t * t * ((BACK_IN_OUT + 1.) * t + BACK_IN_OUT) + 2.) / 2.
+            }
+        }
+    }
+}
+
+/// Vectors apply these per component, with per component edges and bounds like GLSL's `genType`.
+pub trait Functions: Copy {
+    /// `-1`, `0` or `1`, unlike [`f32::signum`] zero stays zero.
+    fn sign(self) -> Self;
+    /// `0` below `edge`, `1` from it on.
+    fn step(self, edge: Self) -> Self;
+    /// Hermite interpolation from `0` at `edge0` to `1` at `edge1`, clamped outside of them.
+    fn smoothstep(self, edge0: Self, edge1: Self) -> Self;
+    /// Like [`Self::smoothstep`] with zero first and second derivatives at the edges.
+    fn smootherstep(self, edge0: Self, edge1: Self) -> Self;
+    fn mix(self, rhs: Self, t: Self) -> Self;
+    /// Where `self` is between `a` and `b`, the inverse of [`Self::mix`]. Not clamped.
+    fn inverse_lerp(self, a: Self, b: Self) -> Self;
+    /// Maps `self` from `from_min..from_max` to `to_min..to_max`, not clamped.
+    fn remap(self, from_min: Self, from_max: Self, to_min: Self, to_max: Self) -> Self;
+    /// Clamped to `0..=1`.
+    fn saturate(self) -> Self;
+    /// Wraps around into `min..max`, also below `min`.
+    fn wrap(self, min: Self, max: Self) -> Self;
+    /// Goes from `0` up to `length` and back down again as `self` grows.
+    fn ping_pong(self, length: Self) -> Self;
+    fn ease(self, easing: Easing) -> Self;
+}
+
+impl Functions for f32 {
+    fn sign(self) -> Self {
+        if self > 0. {
+            1.
+        } else if self < 0. {
+            -1.
+        } else {
+            0.
+        }
+    }
+    fn step(self, edge: Self) -> Self {
+        if self < edge { 0. } else { 1. }
+    }
+    fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+        let t = self.inverse_lerp(edge0, edge1).saturate();
+        t * t * (3. - 2. * t)
+    }
+    fn smootherstep(self, edge0: Self, edge1: Self) -> Self {
+        let t = self.inverse_lerp(edge0, edge1).saturate();
+        t * t * t * (t * (6. * t - 15.) + 10.)
+    }
+    fn mix(self, rhs: Self, t: Self) -> Self {
+        self + (rhs - self) * t
+    }
+    fn inverse_lerp(self, a: Self, b: Self) -> Self {
+        (self - a) / (b - a)
+    }
+    fn remap(self, from_min: Self, from_max: Self, to_min: Self, to_max: Self) -> Self {
+        to_min.mix(to_max, self.inverse_lerp(from_min, from_max))
+    }
+    fn saturate(self) -> Self {
+        self.clamp(0., 1.)
+    }
+    fn wrap(self, min: Self, max: Self) -> Self {
+        let range = max - min;
+        self - range * math::floor((self - min) / range)
+    }
+    fn ping_pong(self, length: Self) -> Self {
+        length - math::abs(self.wrap(0., 2. * length) - length)
+    }
+    fn ease(self, easing: Easing) -> Self {
+        easing.apply(self)
+    }
+}
+
+macro_rules! impl_functions {
+    ($name:ident, [$($field:ident),+]) => {
+        impl Functions for $name {
+            fn sign(self) -> Self {
+                $name { $($field: self.$field.sign()),+ }
+            }
+            fn step(self, edge: Self) -> Self {
+                $name { $($field: self.$field.step(edge.$field)),+ }
+            }
+            fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+                $name { $($field: self.$field.smoothstep(edge0.$field, edge1.$field)),+ }
+            }
+            fn smootherstep(self, edge0: Self, edge1: Self) -> Self {
+                $name { $($field: self.$field.smootherstep(edge0.$field, edge1.$field)),+ }
+            }
+            fn mix(self, rhs: Self, t: Self) -> Self {
+                $name { $($field: self.$field.mix(rhs.$field, t.$field)),+ }
+            }
+            fn inverse_lerp(self, a: Self, b: Self) -> Self {
+                $name { $($field: self.$field.inverse_lerp(a.$field, b.$field)),+ }
+            }
+            fn remap(self, from_min: Self, from_max: Self, to_min: Self, to_max: Self) -> Self {
+                $name {
+                    $($field: self.$field.remap(
+                        from_min.$field,
+                        from_max.$field,
+                        to_min.$field,
+                        to_max.$field,
+                    )),+
+                }
+            }
+            fn saturate(self) -> Self {
+                $name { $($field: self.$field.saturate()),+ }
+            }
+            fn wrap(self, min: Self, max: Self) -> Self {
+                $name { $($field: self.$field.wrap(min.$field, max.$field)),+ }
+            }
+            fn ping_pong(self, length: Self) -> Self {
+                $name { $($field: self.$field.ping_pong(length.$field)),+ }
+            }
+            fn ease(self, easing: Easing) -> Self {
+                $name { $($field: easing.apply(self.$field)),+ }
+            }
+        }
+    };
+}
+
+impl_functions!(Vec2, [x, y]);
+impl_functions!(Vec3, [x, y, z]);
+impl_functions!(Vec4, [x, y, z, w]);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EASINGS: [Easing; 15] = [
+        Easing::QuadIn,
+        Easing::QuadOut,
+        Easing::QuadInOut,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::ElasticIn,
+        Easing::ElasticOut,
+        Easing::ElasticInOut,
+        Easing::BounceIn,
+        Easing::BounceOut,
+        Easing::BounceInOut,
+        Easing::BackIn,
+        Easing::BackOut,
+        Easing::BackInOut,
+    ];
+
+    fn assert_near(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-5, "{a} != {b}");
+    }
+
+    #[test]
+    fn shaping() {
+        assert_eq!((-3f32).sign(), -1.);
+        assert_eq!(0f32.sign(), 0.);
+        assert_eq!(2f32.step(2.), 1.);
+        assert_eq!(1.9f32.step(2.), 0.);
+        assert_eq!(0.5f32.smoothstep(0., 1.), 0.5);
+        assert_eq!(0.25f32.smoothstep(0., 1.), 0.15625);
+        assert_eq!(3f32.smoothstep(0., 2.), 1.);
+        assert_eq!(0.5f32.smootherstep(0., 1.), 0.5);
+        assert_eq!((-1f32).smootherstep(0., 1.), 0.);
+    }
+
+    #[test]
+    fn interpolation() {
+        assert_eq!(2f32.mix(4., 0.25), 2.5);
+        assert_eq!(5f32.inverse_lerp(4., 8.), 0.25);
+        assert_eq!(10f32.inverse_lerp(4., 8.), 1.5);
+        assert_eq!(5f32.remap(4., 8., 0., -8.), -2.);
+        assert_eq!(1.5f32.saturate(), 1.);
+        assert_eq!((-0.5f32).saturate(), 0.);
+    }
+
+    #[test]
+    fn wrapping() {
+        assert_eq!(7f32.wrap(0., 5.), 2.);
+        assert_eq!((-1f32).wrap(0., 5.), 4.);
+        assert_eq!(12f32.wrap(-10., 10.), -8.);
+        assert_eq!(3f32.ping_pong(2.), 1.);
+        assert_eq!(5f32.ping_pong(2.), 1.);
+        assert_eq!((-1f32).ping_pong(2.), 1.);
+    }
+
+    #[test]
+    fn easings_reach_their_ends() {
+        for easing in EASINGS {
+            assert_near(easing.apply(0.), 0.);
+            assert_near(easing.apply(1.), 1.);
+        }
+        assert_eq!(Easing::QuadInOut.apply(0.5), 0.5);
+        assert_eq!(Easing::CubicIn.apply(0.5), 0.125);
+        assert_near(Easing::BounceOut.apply(0.5), 0.765625);
+        assert!(Easing::BackIn.apply(0.2) < 0.);
+        assert!(Easing::ElasticOut.apply(0.2) > 1.);
+    }
+
+    #[test]
+    fn vectors() {
+        let v = Vec3::new(-2., 0.5, 3.);
+        assert_eq!(v.sign(), Vec3::new(-1., 1., 1.));
+        assert_eq!(v.saturate(), Vec3::new(0., 0.5, 1.));
+        assert_eq!(v.step(Vec3::new(0., 1., 3.)), Vec3::new(0., 0., 1.));
+        assert_eq!(
+            Vec2::new(0., 10.).mix(Vec2::new(4., 20.), Vec2::new(0.5, 0.1)),
+            Vec2::new(2., 11.)
+        );
+        assert_eq!(Vec4::splat(0.5).ease(Easing::QuadIn), Vec4::splat(0.25));
+        assert_eq!(
+            Vec2::new(6., -1.).wrap(Vec2::splat(0.), Vec2::splat(5.)),
+            Vec2::new(1., 4.)
+        );
+    }
+}
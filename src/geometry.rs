@@ -0,0 +1,600 @@
+use crate::{
+    bounds::{Aabb3, Rect},
+    vec2::Vec2,
+    vec3::Vec3,
+};
+
+/// Below this a ray counts as parallel to a plane, a triangle or an axis.
+const EPSILON: f32 = 1e-6;
+
+/// Where a ray hits a primitive, `distance` along the ray. For closed shapes `normal` points out
+/// of the surface, for planes, triangles and segments it faces the ray.
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+pub struct Hit<V> {
+    pub distance: f32,
+    pub point: V,
+    pub normal: V,
+}
+
+/// A half-line from `origin`, [`Ray2::new`] normalizes `direction` so that distances are lengths.
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct Ray2 {
+    pub origin: Vec2,
+    pub direction: Vec2,
+}
+
+/// A half-line from `origin`, [`Ray3::new`] normalizes `direction` so that distances are lengths.
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct Ray3 {
+    pub origin: Vec3,
+    pub direction: Vec3,
+}
+
+/// The points `p` with `normal.dot(p) == distance`.
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct Plane {
+    pub normal: Vec3,
+    pub distance: f32,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct Sphere {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct Circle {
+    pub center: Vec2,
+    pub radius: f32,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct Segment2 {
+    pub a: Vec2,
+    pub b: Vec2,
+}
+
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct Segment3 {
+    pub a: Vec3,
+    pub b: Vec3,
+}
+
+/// What the 2D and 3D variants share, `$ball` is `Circle` or `Sphere`, `$bounds` `Rect` or `Aabb3`.
+macro_rules! shared {
+    ($ray:ident, $segment:ident, $ball:ident, $bounds:ident, $vec:ident, [$($field:ident),+]) => {
+        impl $ray {
+            pub fn new(origin: $vec, direction: $vec) -> Self {
+                Self {
+                    origin,
+                    direction: direction.normalize(),
+                }
+            }
+            pub fn at(self, distance: f32) -> $vec {
+                self.origin + self.direction * distance
+            }
+            pub fn closest_point(self, point: $vec) -> $vec {
+                self.at((point - self.origin).dot(self.direction).max(0.))
+            }
+            pub fn distance_to_point(self, point: $vec) -> f32 {
+                (point - self.closest_point(point)).length()
+            }
+            fn intersect_bounds(self, bounds: $bounds) -> Option<Hit<$vec>> {
+                let mut near = f32::NEG_INFINITY;
+                let mut far = f32::INFINITY;
+                let mut near_normal = $vec::default();
+                let mut far_normal = $vec::default();
+                $(
+                    if crate::math::abs(self.direction.$field) < EPSILON {
+                        if self.origin.$field < bounds.min.$field
+                            || self.origin.$field > bounds.max.$field
+                        {
+                            return None;
+                        }
+                    } else {
+                        let to_min = (bounds.min.$field - self.origin.$field) / self.direction.$field;
+                        let to_max = (bounds.max.$field - self.origin.$field) / self.direction.$field;
+                        let (enter, exit, sign) = if self.direction.$field > 0. {
+                            (to_min, to_max, -1.)
+                        } else {
+                            (to_max, to_min, 1.)
+                        };
+                        if enter > near {
+                            near = enter;
+                            near_normal = $vec::default();
+                            near_normal.$field = sign;
+                        }
+                        if exit < far {
+                            far = exit;
+                            far_normal = $vec::default();
+                            far_normal.$field = -sign;
+                        }
+                    }
+                )+
+                if near > far || far < 0. {
+                    return None;
+                }
+                // from inside the bounds the ray hits them on the way out
+                let (distance, normal) = if near >= 0. {
+                    (near, near_normal)
+                } else {
+                    (far, far_normal)
+                };
+                Some(Hit {
+                    distance,
+                    point: self.at(distance),
+                    normal,
+                })
+            }
+            fn intersect_ball(self, ball: $ball) -> Option<Hit<$vec>> {
+                let offset = self.origin - ball.center;
+                let b = offset.dot(self.direction);
+                let c = offset.dot(offset) - ball.radius * ball.radius;
+                // outside and looking away
+                if c > 0. && b > 0. {
+                    return None;
+                }
+                let discriminant = b * b - c;
+                if discriminant < 0. {
+                    return None;
+                }
+                let root = crate::math::sqrt(discriminant);
+                // from inside the ball the ray hits it on the way out
+                let distance = if c > 0. { -b - root } else { -b + root };
+                let point = self.at(distance);
+                Some(Hit {
+                    distance,
+                    point,
+                    normal: (point - ball.center) / ball.radius,
+                })
+            }
+        }
+
+        impl $ball {
+            pub const fn new(center: $vec, radius: f32) -> Self {
+                Self { center, radius }
+            }
+            pub fn contains(self, point: $vec) -> bool {
+                (point - self.center).length_squared() <= self.radius * self.radius
+            }
+            /// `point` itself when it is inside.
+            pub fn closest_point(self, point: $vec) -> $vec {
+                let offset = point - self.center;
+                let length = offset.length();
+                if length <= self.radius {
+                    return point;
+                }
+                self.center + offset * (self.radius / length)
+            }
+            /// 0 inside.
+            pub fn distance_to_point(self, point: $vec) -> f32 {
+                ((point - self.center).length() - self.radius).max(0.)
+            }
+        }
+
+        impl $segment {
+            pub const fn new(a: $vec, b: $vec) -> Self {
+                Self { a, b }
+            }
+            pub fn length(self) -> f32 {
+                (self.b - self.a).length()
+            }
+            pub fn closest_point(self, point: $vec) -> $vec {
+                let edge = self.b - self.a;
+                let length_squared = edge.length_squared();
+                if length_squared == 0. {
+                    return self.a;
+                }
+                let s = ((point - self.a).dot(edge) / length_squared).clamp(0., 1.);
+                self.a + edge * s
+            }
+            pub fn distance_to_point(self, point: $vec) -> f32 {
+                (point - self.closest_point(point)).length()
+            }
+        }
+    };
+}
+
+shared!(Ray2, Segment2, Circle, Rect, Vec2, [x, y]);
+shared!(Ray3, Segment3, Sphere, Aabb3, Vec3, [x, y, z]);
+
+impl Ray2 {
+    pub fn intersect_circle(self, circle: Circle) -> Option<Hit<Vec2>> {
+        self.intersect_ball(circle)
+    }
+    pub fn intersect_rect(self, rect: Rect) -> Option<Hit<Vec2>> {
+        self.intersect_bounds(rect)
+    }
+    pub fn intersect_segment(self, segment: Segment2) -> Option<Hit<Vec2>> {
+        fn perp_dot(a: Vec2, b: Vec2) -> f32 {
+            a.x * b.y - a.y * b.x
+        }
+
+        let edge = segment.b - segment.a;
+        let denominator = perp_dot(self.direction, edge);
+        if crate::math::abs(denominator) < EPSILON {
+            return None;
+        }
+        let offset = segment.a - self.origin;
+        let distance = perp_dot(offset, edge) / denominator;
+        let s = perp_dot(offset, self.direction) / denominator;
+        if distance < 0. || !(0. ..=1.).contains(&s) {
+            return None;
+        }
+        let normal = Vec2::new(-edge.y, edge.x).normalize();
+        Some(Hit {
+            distance,
+            point: self.at(distance),
+            normal: if normal.dot(self.direction) > 0. {
+                -normal
+            } else {
+                normal
+            },
+        })
+    }
+}
+
+impl Ray3 {
+    pub fn intersect_sphere(self, sphere: Sphere) -> Option<Hit<Vec3>> {
+        self.intersect_ball(sphere)
+    }
+    pub fn intersect_aabb(self, aabb: Aabb3) -> Option<Hit<Vec3>> {
+        self.intersect_bounds(aabb)
+    }
+    pub fn intersect_plane(self, plane: Plane) -> Option<Hit<Vec3>> {
+        let denominator = plane.normal.dot(self.direction);
+        if crate::math::abs(denominator) < EPSILON {
+            return None;
+        }
+        let distance = (plane.distance - plane.normal.dot(self.origin)) / denominator;
+        if distance < 0. {
+            return None;
+        }
+        Some(Hit {
+            distance,
+            point: self.at(distance),
+            normal: if denominator < 0. {
+                plane.normal
+            } else {
+                -plane.normal
+            },
+        })
+    }
+    /// Möller–Trumbore, both sides of the triangle count.
+    pub fn intersect_triangle(self, a: Vec3, b: Vec3, c: Vec3) -> Option<Hit<Vec3>> {
+        let edge1 = b - a;
+        let edge2 = c - a;
+        let p = self.direction.cross(edge2);
+        let determinant = edge1.dot(p);
+        if crate::math::abs(determinant) < EPSILON {
+            return None;
+        }
+        let inverse = 1. / determinant;
+        let offset = self.origin - a;
+        let u = offset.dot(p) * inverse;
+        if !(0. ..=1.).contains(&u) {
+            return None;
+        }
+        let q = offset.cross(edge1);
+        let v = self.direction.dot(q) * inverse;
+        if v < 0. || u + v > 1. {
+            return None;
+        }
+        let distance = edge2.dot(q) * inverse;
+        if distance < 0. {
+            return None;
+        }
+        let normal = edge1.cross(edge2).normalize();
+        Some(Hit {
+            distance,
+            point: self.at(distance),
+            normal: if normal.dot(self.direction) > 0. {
+                -normal
+            } else {
+                normal
+            },
+        })
+    }
+}
+
+impl Plane {
+    /// Normalizes `normal`, scaling `distance` along.
+    pub fn new(normal: Vec3, distance: f32) -> Self {
+        let length = normal.length();
+        Self {
+            normal: normal / length,
+            distance: distance / length,
+        }
+    }
+    pub fn from_point_normal(point: Vec3, normal: Vec3) -> Self {
+        let normal = normal.normalize();
+        Self {
+            normal,
+            distance: normal.dot(point),
+        }
+    }
+    /// The normal points to the side from which `a`, `b`, `c` are counter-clockwise.
+    pub fn from_points(a: Vec3, b: Vec3, c: Vec3) -> Self {
+        Self::from_point_normal(a, (b - a).cross(c - a))
+    }
+    /// Positive on the side `normal` points to.
+    pub fn signed_distance(self, point: Vec3) -> f32 {
+        self.normal.dot(point) - self.distance
+    }
+    pub fn distance_to_point(self, point: Vec3) -> f32 {
+        crate::math::abs(self.signed_distance(point))
+    }
+    pub fn closest_point(self, point: Vec3) -> Vec3 {
+        point - self.normal * self.signed_distance(point)
+    }
+}
+
+#[cfg(feature = "lua")]
+impl<V: mlua::FromLua> mlua::FromLua for Hit<V> {
+    fn from_lua(value: mlua::Value, _lua: &mlua::Lua) -> mlua::Result<Self> {
+        let table = value.as_table().ok_or_else(|| {
+            lua_error::lua_error!(
+                "`{}` should be a table, got `{}` instead",
+                stringify!(Hit),
+                value.type_name()
+            )
+        })?;
+        Ok(Hit {
+            distance: mlua::ErrorContext::with_context(table.get("distance"), |_| {
+                "could not get `Hit`s field `distance`"
+            })?,
+            point: mlua::ErrorContext::with_context(table.get("point"), |_| {
+                "could not get `Hit`s field `point`"
+            })?,
+            normal: mlua::ErrorContext::with_context(table.get("normal"), |_| {
+                "could not get `Hit`s field `normal`"
+            })?,
+        })
+    }
+}
+
+/// A plain `{ distance, point, normal }` table.
+#[cfg(feature = "lua")]
+impl<V: mlua::IntoLua> mlua::IntoLua for Hit<V> {
+    fn into_lua(self, lua: &mlua::Lua) -> mlua::Result<mlua::Value> {
+        let table = lua.create_table()?;
+        table.set("distance", self.distance)?;
+        table.set("point", self.point)?;
+        table.set("normal", self.normal)?;
+        Ok(mlua::Value::Table(table))
+    }
+}
+
+/// Lua conversions of the primitives, tables of their two fields with the type's metatable.
+macro_rules! lua_conversions {
+    ($name:ident, $metatable:literal, $first:ident, $second:ident) => {
+        #[cfg(feature = "lua")]
+        impl mlua::FromLua for $name {
+            fn from_lua(value: mlua::Value, _lua: &mlua::Lua) -> mlua::Result<Self> {
+                let table = value.as_table().ok_or_else(|| {
+                    lua_error::lua_error!(
+                        "`{}` should be a table, got `{}` instead",
+                        stringify!($name),
+                        value.type_name()
+                    )
+                })?;
+                Ok($name {
+                    $first: mlua::ErrorContext::with_context(table.raw_get(1i32), |_| {
+                        concat!(
+                            "could not get `",
+                            stringify!($name),
+                            "`s field `",
+                            stringify!($first),
+                            "`"
+                        )
+                    })?,
+                    $second: mlua::ErrorContext::with_context(table.raw_get(2i32), |_| {
+                        concat!(
+                            "could not get `",
+                            stringify!($name),
+                            "`s field `",
+                            stringify!($second),
+                            "`"
+                        )
+                    })?,
+                })
+            }
+        }
+
+        #[cfg(feature = "lua")]
+        impl mlua::IntoLua for $name {
+            fn into_lua(self, lua: &mlua::Lua) -> mlua::Result<mlua::Value> {
+                let table = lua.create_table()?;
+                let inner_table = lua
+                    .globals()
+                    .get::<Option<mlua::Table>>("__inner")?
+                    .ok_or_else(|| lua_error::lua_error!("could not get __inner"))?;
+                let meth_table = mlua::ErrorContext::with_context(
+                    inner_table.get::<mlua::Table>("meth"),
+                    |_| "could not get __inner.meth table",
+                )?;
+                let metatable = meth_table
+                    .get::<Option<mlua::Table>>($metatable)?
+                    .ok_or_else(|| {
+                        lua_error::lua_error!("could not get metatable {}", $metatable)
+                    })?;
+                table.set_metatable(Some(metatable));
+                table.raw_set(1i32, self.$first)?;
+                table.raw_set(2i32, self.$second)?;
+                Ok(mlua::Value::Table(table))
+            }
+        }
+    };
+}
+
+lua_conversions!(Ray2, "ray2_metatable", origin, direction);
+lua_conversions!(Ray3, "ray3_metatable", origin, direction);
+lua_conversions!(Plane, "plane_metatable", normal, distance);
+lua_conversions!(Sphere, "sphere_metatable", center, radius);
+lua_conversions!(Circle, "circle_metatable", center, radius);
+lua_conversions!(Segment2, "segment2_metatable", a, b);
+lua_conversions!(Segment3, "segment3_metatable", a, b);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-5, "{a} != {b}");
+    }
+
+    #[test]
+    fn ray_plane() {
+        let ray = Ray3::new(Vec3::new(0., 5., 0.), Vec3::new(0., -2., 0.));
+        let plane = Plane::from_point_normal(Vec3::new(0., 1., 0.), Vec3::new(0., 1., 0.));
+        let hit = ray.intersect_plane(plane).unwrap();
+        assert_eq!(hit.distance, 4.);
+        assert_eq!(hit.point, Vec3::new(0., 1., 0.));
+        assert_eq!(hit.normal, Vec3::new(0., 1., 0.));
+
+        let below = Ray3::new(Vec3::new(0., -5., 0.), Vec3::new(0., 1., 0.));
+        assert_eq!(
+            below.intersect_plane(plane).unwrap().normal,
+            Vec3::new(0., -1., 0.)
+        );
+        let away = Ray3::new(Vec3::new(0., 5., 0.), Vec3::new(0., 1., 0.));
+        assert_eq!(away.intersect_plane(plane), None);
+        let parallel = Ray3::new(Vec3::new(0., 5., 0.), Vec3::new(1., 0., 0.));
+        assert_eq!(parallel.intersect_plane(plane), None);
+    }
+
+    #[test]
+    fn ray_sphere() {
+        let sphere = Sphere::new(Vec3::new(0., 0., 10.), 2.);
+        let ray = Ray3::new(Vec3::splat(0.), Vec3::new(0., 0., 1.));
+        let hit = ray.intersect_sphere(sphere).unwrap();
+        assert_eq!(hit.distance, 8.);
+        assert_eq!(hit.normal, Vec3::new(0., 0., -1.));
+
+        let inside = Ray3::new(Vec3::new(0., 0., 10.), Vec3::new(0., 1., 0.));
+        let hit = inside.intersect_sphere(sphere).unwrap();
+        assert_eq!(hit.distance, 2.);
+        assert_eq!(hit.normal, Vec3::new(0., 1., 0.));
+
+        let miss = Ray3::new(Vec3::new(3., 0., 0.), Vec3::new(0., 0., 1.));
+        assert_eq!(miss.intersect_sphere(sphere), None);
+        let behind = Ray3::new(Vec3::splat(0.), Vec3::new(0., 0., -1.));
+        assert_eq!(behind.intersect_sphere(sphere), None);
+    }
+
+    #[test]
+    fn ray_aabb() {
+        let aabb = Aabb3::from_min_max(Vec3::splat(-1.), Vec3::splat(1.));
+        let ray = Ray3::new(Vec3::new(-5., 0.5, 0.), Vec3::new(1., 0., 0.));
+        let hit = ray.intersect_aabb(aabb).unwrap();
+        assert_eq!(hit.distance, 4.);
+        assert_eq!(hit.point, Vec3::new(-1., 0.5, 0.));
+        assert_eq!(hit.normal, Vec3::new(-1., 0., 0.));
+
+        let inside = Ray3::new(Vec3::splat(0.), Vec3::new(0., 0., -1.));
+        let hit = inside.intersect_aabb(aabb).unwrap();
+        assert_eq!(hit.distance, 1.);
+        assert_eq!(hit.normal, Vec3::new(0., 0., -1.));
+
+        let miss = Ray3::new(Vec3::new(-5., 2., 0.), Vec3::new(1., 0., 0.));
+        assert_eq!(miss.intersect_aabb(aabb), None);
+        let diagonal = Ray3::new(Vec3::new(-5., -8., 0.), Vec3::new(1., 1., 0.));
+        assert_eq!(diagonal.intersect_aabb(aabb), None);
+    }
+
+    #[test]
+    fn ray_triangle() {
+        let (a, b, c) = (
+            Vec3::new(0., 0., 0.),
+            Vec3::new(2., 0., 0.),
+            Vec3::new(0., 2., 0.),
+        );
+        let ray = Ray3::new(Vec3::new(0.5, 0.5, 3.), Vec3::new(0., 0., -1.));
+        let hit = ray.intersect_triangle(a, b, c).unwrap();
+        assert_close(hit.distance, 3.);
+        assert_eq!(hit.point, Vec3::new(0.5, 0.5, 0.));
+        assert_eq!(hit.normal, Vec3::new(0., 0., 1.));
+
+        let back = Ray3::new(Vec3::new(0.5, 0.5, -3.), Vec3::new(0., 0., 1.));
+        assert_eq!(
+            back.intersect_triangle(a, b, c).unwrap().normal,
+            Vec3::new(0., 0., -1.)
+        );
+        let outside = Ray3::new(Vec3::new(1.5, 1.5, 3.), Vec3::new(0., 0., -1.));
+        assert_eq!(outside.intersect_triangle(a, b, c), None);
+    }
+
+    #[test]
+    fn ray2() {
+        let ray = Ray2::new(Vec2::new(0., 0.), Vec2::new(2., 0.));
+        let hit = ray
+            .intersect_circle(Circle::new(Vec2::new(5., 0.), 1.))
+            .unwrap();
+        assert_eq!(hit.distance, 4.);
+        assert_eq!(hit.normal, Vec2::new(-1., 0.));
+
+        let rect = Rect::from_min_max(Vec2::new(2., -1.), Vec2::new(3., 1.));
+        let hit = ray.intersect_rect(rect).unwrap();
+        assert_eq!(hit.point, Vec2::new(2., 0.));
+        assert_eq!(hit.normal, Vec2::new(-1., 0.));
+
+        let segment = Segment2::new(Vec2::new(1., -1.), Vec2::new(1., 1.));
+        let hit = ray.intersect_segment(segment).unwrap();
+        assert_eq!(hit.distance, 1.);
+        assert_eq!(hit.normal, Vec2::new(-1., 0.));
+        let short = Segment2::new(Vec2::new(1., 1.), Vec2::new(1., 2.));
+        assert_eq!(ray.intersect_segment(short), None);
+    }
+
+    #[test]
+    fn closest_points_and_distances() {
+        let ray = Ray3::new(Vec3::splat(0.), Vec3::new(1., 0., 0.));
+        assert_eq!(
+            ray.closest_point(Vec3::new(3., 4., 0.)),
+            Vec3::new(3., 0., 0.)
+        );
+        assert_eq!(ray.closest_point(Vec3::new(-3., 4., 0.)), Vec3::splat(0.));
+        assert_eq!(ray.distance_to_point(Vec3::new(-3., 4., 0.)), 5.);
+
+        let segment = Segment2::new(Vec2::new(0., 0.), Vec2::new(2., 0.));
+        assert_eq!(segment.closest_point(Vec2::new(1., 3.)), Vec2::new(1., 0.));
+        assert_eq!(segment.closest_point(Vec2::new(5., 4.)), Vec2::new(2., 0.));
+        assert_eq!(segment.distance_to_point(Vec2::new(5., 4.)), 5.);
+        let point = Segment3::new(Vec3::splat(1.), Vec3::splat(1.));
+        assert_eq!(point.closest_point(Vec3::splat(0.)), Vec3::splat(1.));
+
+        let plane = Plane::from_points(
+            Vec3::new(0., 2., 0.),
+            Vec3::new(0., 2., 1.),
+            Vec3::new(1., 2., 0.),
+        );
+        assert_eq!(plane.normal, Vec3::new(0., 1., 0.));
+        assert_eq!(plane.signed_distance(Vec3::new(5., -1., 5.)), -3.);
+        assert_eq!(plane.distance_to_point(Vec3::new(5., -1., 5.)), 3.);
+        assert_eq!(
+            plane.closest_point(Vec3::new(5., -1., 5.)),
+            Vec3::new(5., 2., 5.)
+        );
+        assert_eq!(Plane::new(Vec3::new(0., 2., 0.), 4.), plane);
+
+        let sphere = Sphere::new(Vec3::splat(0.), 1.);
+        assert_eq!(
+            sphere.closest_point(Vec3::new(0., 0., 3.)),
+            Vec3::new(0., 0., 1.)
+        );
+        assert_eq!(sphere.distance_to_point(Vec3::new(0., 0., 3.)), 2.);
+        assert_eq!(sphere.distance_to_point(Vec3::new(0., 0., 0.5)), 0.);
+        let circle = Circle::new(Vec2::new(1., 1.), 2.);
+        assert!(circle.contains(Vec2::new(2., 2.)));
+        assert!(!circle.contains(Vec2::new(3., 3.)));
+        assert_eq!(circle.closest_point(Vec2::new(1., 5.)), Vec2::new(1., 3.));
+    }
+}
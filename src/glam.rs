@@ -0,0 +1,138 @@
+//! Conversions from and to the [`glam`](https://docs.rs/glam) types. Both store matrices
+//! column-major, `x_axis` is glam's `x_axis`. The conversions keep every component in place, so
+//! the transform constructors of [`Mat3`] convert to the transpose of glam's.
+
+use crate::{
+    mat2::Mat2,
+    mat3::Mat3,
+    mat4::Mat4,
+    vec2::{IVec2, UVec2, Vec2},
+    vec3::{IVec3, UVec3, Vec3},
+    vec4::{IVec4, UVec4, Vec4},
+};
+
+macro_rules! impl_vector {
+    ($name:ident, $glam:ident, [$($field:ident),+]) => {
+        impl From<$name> for ::glam::$glam {
+            fn from(value: $name) -> Self {
+                Self::new($(value.$field),+)
+            }
+        }
+
+        impl From<::glam::$glam> for $name {
+            fn from(value: ::glam::$glam) -> Self {
+                Self::new($(value.$field),+)
+            }
+        }
+    };
+}
+
+macro_rules! impl_matrix {
+    ($name:ident, $glam:ident) => {
+        impl From<$name> for ::glam::$glam {
+            fn from(value: $name) -> Self {
+                Self::from_cols_array(&value.to_cols_array())
+            }
+        }
+
+        impl From<::glam::$glam> for $name {
+            fn from(value: ::glam::$glam) -> Self {
+                Self::from_cols_array(&value.to_cols_array())
+            }
+        }
+    };
+}
+
+impl_vector!(UVec2, UVec2, [x, y]);
+impl_vector!(IVec2, IVec2, [x, y]);
+impl_vector!(Vec2, Vec2, [x, y]);
+impl_vector!(UVec3, UVec3, [x, y, z]);
+impl_vector!(IVec3, IVec3, [x, y, z]);
+impl_vector!(Vec3, Vec3, [x, y, z]);
+impl_vector!(UVec4, UVec4, [x, y, z, w]);
+impl_vector!(IVec4, IVec4, [x, y, z, w]);
+impl_vector!(Vec4, Vec4, [x, y, z, w]);
+
+impl_matrix!(Mat2, Mat2);
+impl_matrix!(Mat3, Mat3);
+impl_matrix!(Mat4, Mat4);
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        mat2::Mat2,
+        mat3::Mat3,
+        mat4::Mat4,
+        vec2::{UVec2, Vec2},
+        vec3::{IVec3, Vec3},
+        vec4::Vec4,
+    };
+
+    #[test]
+    fn vectors() {
+        assert_eq!(
+            glam::Vec3::from(Vec3::new(1., 2., 3.)),
+            glam::Vec3::new(1., 2., 3.)
+        );
+        assert_eq!(
+            Vec4::from(glam::Vec4::new(1., 2., 3., 4.)),
+            Vec4::new(1., 2., 3., 4.)
+        );
+        assert_eq!(
+            IVec3::from(glam::IVec3::new(-1, 0, 1)),
+            IVec3::new(-1, 0, 1)
+        );
+        assert_eq!(glam::UVec2::from(UVec2::new(1, 2)), glam::UVec2::new(1, 2));
+    }
+
+    #[test]
+    fn matrices_are_column_major() {
+        let matrix = Mat3::from_cols_array(&[1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let glam_matrix = glam::Mat3::from(matrix);
+        assert_eq!(glam_matrix.x_axis, glam::Vec3::new(1., 2., 3.));
+        assert_eq!(glam_matrix.z_axis, glam::Vec3::new(7., 8., 9.));
+        assert_eq!(glam_matrix.row(0), glam::Vec3::new(1., 4., 7.));
+        assert_eq!(Mat3::from(glam_matrix), matrix);
+
+        let vector = Vec3::new(1., -2., 3.);
+        assert_eq!(
+            Vec3::from(glam_matrix * glam::Vec3::from(vector)),
+            matrix * vector
+        );
+        let other = Mat3::from_cols_array(&[9., 8., 7., 6., 5., 4., 3., 2., 1.]);
+        assert_eq!(
+            Mat3::from(glam_matrix * glam::Mat3::from(other)),
+            matrix * other
+        );
+    }
+
+    #[test]
+    fn translations_are_transposed() {
+        let translation = Mat3::from_translation(Vec2::new(2., -3.));
+        let glam_translation = glam::Mat3::from_translation(glam::Vec2::new(2., -3.));
+        assert_eq!(glam::Mat3::from(translation), glam_translation.transpose());
+        assert_eq!(Mat3::from(glam_translation.transpose()), translation);
+    }
+
+    #[test]
+    fn matrices_keep_their_products() {
+        let matrix = Mat2::new(1., 2., 3., 4.);
+        let vector = Vec2::new(5., 6.);
+        assert_eq!(
+            Vec2::from(glam::Mat2::from(matrix) * glam::Vec2::from(vector)),
+            matrix * vector
+        );
+
+        let array: [f32; 16] = core::array::from_fn(|index| index as f32);
+        let matrix = Mat4::from_cols_array(&array);
+        assert_eq!(
+            glam::Mat4::from(matrix).w_axis,
+            glam::Vec4::new(12., 13., 14., 15.)
+        );
+        let vector = Vec4::new(1., 2., 3., 4.);
+        assert_eq!(
+            Vec4::from(glam::Mat4::from(matrix) * glam::Vec4::from(vector)),
+            matrix * vector
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+#[cfg(not(feature = "luau"))]
+use mlua::{DebugEvent, HookTriggers};
+use mlua::{Lua, VmState};
+
+use crate::coverage::Coverage;
+
+/// How many VM instructions run between two limit checks.
+pub const INSTRUCTION_STEP: u32 = 1000;
+/// Prefix of the errors raised when a test exceeds its limits, lopa-test reports these as timeouts.
+pub const TIMEOUT_PREFIX: &str = "lopa-test timeout:";
+
+/// Time and instruction limits of the currently running test.
+#[derive(Debug, Clone)]
+pub struct Limits {
+    started: Instant,
+    timeout: Option<Duration>,
+    max_instructions: Option<u64>,
+    instructions: u64,
+    exceeded: bool,
+}
+
+impl Limits {
+    pub fn new(timeout: Option<Duration>, max_instructions: Option<u64>) -> Self {
+        Self {
+            started: Instant::now(),
+            timeout,
+            max_instructions,
+            instructions: 0,
+            exceeded: false,
+        }
+    }
+
+    /// Accounts for another `INSTRUCTION_STEP` instructions. Fails only once, so that the error
+    /// handler producing the traceback can still run.
+    pub fn step(&mut self) -> Result<(), String> {
+        self.step_by(INSTRUCTION_STEP as u64)
+    }
+
+    pub fn step_by(&mut self, instructions: u64) -> Result<(), String> {
+        if self.exceeded {
+            return Ok(());
+        }
+        self.instructions += instructions;
+        if let Some(max_instructions) = self.max_instructions
+            && self.instructions > max_instructions
+        {
+            self.exceeded = true;
+            return Err(format!(
+                "{TIMEOUT_PREFIX} exceeded the budget of {max_instructions} instructions"
+            ));
+        }
+        if let Some(timeout) = self.timeout
+            && self.started.elapsed() > timeout
+        {
+            self.exceeded = true;
+            return Err(format!(
+                "{TIMEOUT_PREFIX} exceeded the time limit of {:.3} seconds",
+                timeout.as_secs_f64()
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Luau has no debug hooks, only interrupts at function calls and loop iterations. Each one counts
+/// as a single instruction and line coverage is not available.
+#[cfg(feature = "luau")]
+pub fn install(
+    lua: &Lua,
+    coverage: Option<Arc<Mutex<Coverage>>>,
+    limits: Arc<Mutex<Option<Limits>>>,
+) -> mlua::Result<()> {
+    if coverage.is_some() {
+        return Err(lua_error::lua_error!(
+            "line coverage is not supported on the Luau backend"
+        ));
+    }
+    lua.set_interrupt(move |_| {
+        if let Some(limits) = limits.lock().unwrap().as_mut() {
+            limits.step_by(1).map_err(mlua::Error::runtime)?;
+        }
+        Ok(VmState::Continue)
+    });
+    Ok(())
+}
+
+/// Installs the debug hook of a test state. A Lua state has a single hook, so it both records
+/// coverage and enforces the limits set through `limits`.
+#[cfg(not(feature = "luau"))]
+pub fn install(
+    lua: &Lua,
+    coverage: Option<Arc<Mutex<Coverage>>>,
+    limits: Arc<Mutex<Option<Limits>>>,
+) -> mlua::Result<()> {
+    // hooks are not called from JIT compiled traces
+    lua.load("if jit then jit.off() end").exec()?;
+
+    let mut triggers = HookTriggers::new().every_nth_instruction(INSTRUCTION_STEP);
+    if coverage.is_some() {
+        triggers = triggers.every_line();
+    }
+    lua.set_hook(triggers, move |_, debug| {
+        match debug.event() {
+            DebugEvent::Line => {
+                let line = debug.curr_line();
+                if let Some(coverage) = &coverage
+                    && let Some(path) = debug.source().source.and_then(|source| {
+                        source
+                            .strip_prefix('@')
+                            .map(|path| path.trim_start_matches("./").to_owned())
+                    })
+                    && line > 0
+                {
+                    coverage.lock().unwrap().record(&path, line as usize);
+                }
+            }
+            DebugEvent::Count => {
+                if let Some(limits) = limits.lock().unwrap().as_mut() {
+                    limits.step().map_err(mlua::Error::runtime)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(VmState::Continue)
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{INSTRUCTION_STEP, Limits};
+
+    #[test]
+    fn instruction_budget_fails_once() {
+        let mut limits = Limits::new(None, Some(INSTRUCTION_STEP as u64 * 2));
+        assert!(limits.step().is_ok());
+        assert!(limits.step().is_ok());
+        assert!(limits.step().is_err());
+        assert!(limits.step().is_ok());
+    }
+}
@@ -0,0 +1,294 @@
+//! Writes values with the std140 and std430 layout rules of GLSL buffer blocks.
+//!
+//! Members are aligned like the spec says, a `Vec3` starts on 16 bytes but a following scalar
+//! still fits into its last 4 bytes. Arrays and matrix columns are padded to their stride, so a
+//! `Mat3` takes 48 bytes and `[Vec3]` 16 bytes per element. Values are written little-endian.
+
+use crate::{
+    mat2::Mat2,
+    mat3::Mat3,
+    mat4::Mat4,
+    vec2::{IVec2, UVec2, Vec2},
+    vec3::{IVec3, UVec3, Vec3},
+    vec4::{IVec4, UVec4, Vec4},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Layout {
+    /// Uniform blocks, arrays and matrix columns align to 16 bytes.
+    Std140,
+    /// Shader storage blocks, arrays and matrix columns align to their element.
+    Std430,
+}
+
+impl Layout {
+    fn array_alignment(self, alignment: usize) -> usize {
+        match self {
+            Layout::Std140 => alignment.max(16),
+            Layout::Std430 => alignment,
+        }
+    }
+}
+
+/// Values that can be members of a block.
+pub trait Member {
+    fn alignment(layout: Layout) -> usize;
+    /// Writes the value, [`Writer`] has already aligned it.
+    fn write_to(&self, writer: &mut Writer);
+}
+
+#[derive(Debug, Clone)]
+pub struct Writer {
+    layout: Layout,
+    bytes: Vec<u8>,
+    alignment: usize,
+}
+
+impl Writer {
+    pub fn new(layout: Layout) -> Self {
+        Self {
+            layout,
+            bytes: Vec::new(),
+            alignment: 4,
+        }
+    }
+    pub fn std140() -> Self {
+        Self::new(Layout::Std140)
+    }
+    pub fn std430() -> Self {
+        Self::new(Layout::Std430)
+    }
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+    /// Where the next member would start if it needed no alignment.
+    pub fn offset(&self) -> usize {
+        self.bytes.len()
+    }
+    pub fn write<T: Member>(&mut self, value: &T) -> &mut Self {
+        self.align(T::alignment(self.layout));
+        value.write_to(self);
+        self
+    }
+    pub fn write_array<T: Member>(&mut self, values: &[T]) -> &mut Self {
+        let alignment = self.layout.array_alignment(T::alignment(self.layout));
+        self.align(alignment);
+        for value in values {
+            value.write_to(self);
+            self.align(alignment);
+        }
+        self
+    }
+    /// The bytes of the block, padded to the alignment of its largest member like a struct.
+    pub fn finish(mut self) -> Vec<u8> {
+        let alignment = self.layout.array_alignment(self.alignment);
+        self.align(alignment);
+        self.bytes
+    }
+    fn align(&mut self, alignment: usize) {
+        self.alignment = self.alignment.max(alignment);
+        self.bytes
+            .resize(self.bytes.len().next_multiple_of(alignment), 0);
+    }
+    fn push(&mut self, bytes: [u8; 4]) {
+        self.bytes.extend_from_slice(&bytes);
+    }
+}
+
+macro_rules! impl_scalar {
+    ($($type:ty),+) => {
+        $(
+            impl Member for $type {
+                fn alignment(_layout: Layout) -> usize {
+                    4
+                }
+                fn write_to(&self, writer: &mut Writer) {
+                    writer.push(self.to_le_bytes());
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_vector {
+    ($name:ident, $alignment:literal, [$($field:ident),+]) => {
+        impl Member for $name {
+            fn alignment(_layout: Layout) -> usize {
+                $alignment
+            }
+            fn write_to(&self, writer: &mut Writer) {
+                $(writer.push(self.$field.to_le_bytes());)+
+            }
+        }
+    };
+}
+
+macro_rules! impl_matrix {
+    ($name:ident, $column:ident, [$($axis:ident),+]) => {
+        /// Written like an array of its columns.
+        impl Member for $name {
+            fn alignment(layout: Layout) -> usize {
+                layout.array_alignment($column::alignment(layout))
+            }
+            fn write_to(&self, writer: &mut Writer) {
+                writer.write_array(&[$(self.$axis),+]);
+            }
+        }
+    };
+}
+
+impl_scalar!(f32, i32, u32);
+
+impl_vector!(UVec2, 8, [x, y]);
+impl_vector!(IVec2, 8, [x, y]);
+impl_vector!(Vec2, 8, [x, y]);
+impl_vector!(UVec3, 16, [x, y, z]);
+impl_vector!(IVec3, 16, [x, y, z]);
+impl_vector!(Vec3, 16, [x, y, z]);
+impl_vector!(UVec4, 16, [x, y, z, w]);
+impl_vector!(IVec4, 16, [x, y, z, w]);
+impl_vector!(Vec4, 16, [x, y, z, w]);
+
+impl_matrix!(Mat2, Vec2, [x_axis, y_axis]);
+impl_matrix!(Mat3, Vec3, [x_axis, y_axis, z_axis]);
+impl_matrix!(Mat4, Vec4, [x_axis, y_axis, z_axis, w_axis]);
+
+/// `uniform_block(layout, members)` of the Lua module, `members` is a sequence of
+/// `{ glsl_type, value }` pairs like `{ "vec3", position }` or `{ "mat4[]", transforms }`.
+/// Returns the block as a byte string.
+#[cfg(feature = "lua")]
+pub(crate) fn uniform_block(
+    lua: &mlua::Lua,
+    (layout, members): (String, mlua::Table),
+) -> mlua::Result<mlua::String> {
+    fn member<T: Member + mlua::FromLua>(
+        writer: &mut Writer,
+        value: mlua::Value,
+        lua: &mlua::Lua,
+        array: bool,
+    ) -> mlua::Result<()> {
+        if array {
+            writer.write_array(&<Vec<T> as mlua::FromLua>::from_lua(value, lua)?);
+        } else {
+            writer.write(&T::from_lua(value, lua)?);
+        }
+        Ok(())
+    }
+
+    let mut writer = match layout.as_str() {
+        "std140" => Writer::std140(),
+        "std430" => Writer::std430(),
+        _ => {
+            return Err(lua_error::lua_error!(
+                "unknown layout `{layout}`, expected `std140` or `std430`"
+            ));
+        }
+    };
+    for (index, pair) in members.sequence_values::<mlua::Table>().enumerate() {
+        let pair = mlua::ErrorContext::with_context(pair, |_| {
+            format!("member {} should be a `{{ type, value }}` table", index + 1)
+        })?;
+        let glsl_type: String = pair.get(1)?;
+        let value: mlua::Value = pair.get(2)?;
+        let (name, array) = match glsl_type.strip_suffix("[]") {
+            Some(name) => (name, true),
+            None => (glsl_type.as_str(), false),
+        };
+        let result = match name {
+            "float" => member::<f32>(&mut writer, value, lua, array),
+            "int" => member::<i32>(&mut writer, value, lua, array),
+            "uint" => member::<u32>(&mut writer, value, lua, array),
+            "vec2" => member::<Vec2>(&mut writer, value, lua, array),
+            "ivec2" => member::<IVec2>(&mut writer, value, lua, array),
+            "uvec2" => member::<UVec2>(&mut writer, value, lua, array),
+            "vec3" => member::<Vec3>(&mut writer, value, lua, array),
+            "ivec3" => member::<IVec3>(&mut writer, value, lua, array),
+            "uvec3" => member::<UVec3>(&mut writer, value, lua, array),
+            "vec4" => member::<Vec4>(&mut writer, value, lua, array),
+            "ivec4" => member::<IVec4>(&mut writer, value, lua, array),
+            "uvec4" => member::<UVec4>(&mut writer, value, lua, array),
+            "mat2" => member::<Mat2>(&mut writer, value, lua, array),
+            "mat3" => member::<Mat3>(&mut writer, value, lua, array),
+            "mat4" => member::<Mat4>(&mut writer, value, lua, array),
+            _ => Err(lua_error::lua_error!("unknown member type `{glsl_type}`")),
+        };
+        mlua::ErrorContext::with_context(result, |_| {
+            format!("could not write member {} of type `{glsl_type}`", index + 1)
+        })?;
+    }
+    lua.create_string(writer.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn floats(bytes: &[u8]) -> Vec<f32> {
+        bytes
+            .chunks(4)
+            .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn vectors_align_to_16_bytes() {
+        let mut writer = Writer::std140();
+        writer.write(&1.0f32).write(&Vec3::new(2., 3., 4.));
+        assert_eq!(writer.offset(), 28);
+        writer.write(&5.0f32).write(&Vec2::new(6., 7.));
+        assert_eq!(
+            floats(&writer.finish()),
+            [1., 0., 0., 0., 2., 3., 4., 5., 6., 7., 0., 0.]
+        );
+    }
+
+    #[test]
+    fn arrays() {
+        let mut writer = Writer::std140();
+        writer.write_array(&[1.0f32, 2.0]);
+        writer.write_array(&[Vec3::splat(3.), Vec3::splat(4.)]);
+        assert_eq!(writer.finish().len(), 32 + 32);
+
+        let mut writer = Writer::std430();
+        writer.write_array(&[1.0f32, 2.0]);
+        writer.write_array(&[Vec3::splat(3.), Vec3::splat(4.)]);
+        assert_eq!(
+            floats(&writer.finish()),
+            [1., 2., 0., 0., 3., 3., 3., 0., 4., 4., 4., 0.]
+        );
+    }
+
+    #[test]
+    fn matrices_pad_their_columns() {
+        let matrix = Mat3::from_cols_array(&[1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        for mut writer in [Writer::std140(), Writer::std430()] {
+            writer.write(&matrix);
+            assert_eq!(
+                floats(&writer.finish()),
+                [1., 2., 3., 0., 4., 5., 6., 0., 7., 8., 9., 0.]
+            );
+        }
+
+        let matrix = Mat2::new(1., 2., 3., 4.);
+        let mut writer = Writer::std140();
+        writer.write(&matrix);
+        assert_eq!(floats(&writer.finish()), [1., 2., 0., 0., 3., 4., 0., 0.]);
+        let mut writer = Writer::std430();
+        writer.write(&1.0f32).write(&matrix);
+        assert_eq!(floats(&writer.finish()), [1., 0., 1., 2., 3., 4.]);
+
+        let mut writer = Writer::std140();
+        writer.write(&Mat4::splat(1.));
+        assert_eq!(writer.finish().len(), 64);
+    }
+
+    #[test]
+    fn integers() {
+        let mut writer = Writer::std430();
+        writer.write(&IVec2::new(-1, 2)).write(&7u32);
+        assert_eq!(
+            writer.finish(),
+            [255, 255, 255, 255, 2, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+}
@@ -1,3 +1,16 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or the `libm` feature has to be enabled for the float functions");
+#[cfg(any(
+    all(feature = "luajit", feature = "lua54"),
+    all(feature = "luajit", feature = "luau"),
+    all(feature = "lua54", feature = "luau"),
+))]
+compile_error!("only one Lua backend feature can be enabled, `luajit`, `lua54` or `luau`");
+
+mod math;
+
 pub mod vec2;
 pub mod vec3;
 pub mod vec4;
@@ -6,26 +19,30 @@ pub mod mat2;
 pub mod mat3;
 pub mod mat4;
 
-pub fn apply(lua: &mut mlua::Lua) -> mlua::Result<()> {
-    lua.load(
-        r"
-local inner = _G['__inner']
-local inner = inner ~= nil and inner or {}
-inner.meth = {}
-_G['__inner'] = inner
-        ",
-    )
-    .exec()?;
-    lua.load(include_str!("lua/meth/vec2.lua")).exec()?;
-    lua.load(include_str!("lua/meth/ivec2.lua")).exec()?;
-    lua.load(include_str!("lua/meth/vec3.lua")).exec()?;
-    lua.load(include_str!("lua/meth/ivec3.lua")).exec()?;
-    lua.load(include_str!("lua/meth/vec4.lua")).exec()?;
-    lua.load(include_str!("lua/meth/ivec4.lua")).exec()?;
-    lua.load(include_str!("lua/meth/mat2.lua")).exec()?;
-    lua.load(include_str!("lua/meth/mat3.lua")).exec()?;
-    lua.load(include_str!("lua/meth/mat4.lua")).exec()?;
-
-    // lua.load(include_str!("lua/meth/test.lua")).exec()?;
-    Ok(())
-}
+pub mod bounds;
+pub mod curves;
+pub mod functions;
+pub mod geometry;
+pub mod noise;
+
+#[cfg(feature = "std")]
+pub mod layout;
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+#[cfg(feature = "lua")]
+mod bindings;
+#[cfg(feature = "lua")]
+pub use bindings::*;
+#[cfg(feature = "luau")]
+mod luau;
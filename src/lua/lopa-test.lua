@@ -1,3 +1,8 @@
+local snapshot = require("lopa-test.snapshot")
+local property = require("lopa-test.property")
+local format = require("lopa-test.format")
+local spies = require("lopa-test.spy")
+
 ---Deeply compare two objects.
 ---@param a any
 ---@param b any
@@ -54,6 +59,18 @@ end
 ---@field name string
 ---@field fun fun()
 ---@field full_name string?
+---@field timeout number? seconds
+---@field instructions integer?
+
+---Limits of a test, or defaults for every test of a module.
+---@class lopa-test.limits
+---@field timeout number? seconds
+---@field instructions integer?
+
+---A test function with its own limits, `{ fun, timeout = 0.5 }`.
+---@class lopa-test.limited_test: lopa-test.limits
+---@field [1] fun()?
+---@field fun fun()?
 
 ---@class lopa-test.case
 ---@field name string
@@ -63,22 +80,236 @@ end
 ---@field name string
 ---@field error any
 
+---@param label string
+---@param value any
+---@return string
+local function labeled(label, value)
+	local padding = string.rep(" ", #label + 2)
+	return label .. ": " .. format.show(value):gsub("\n", "\n" .. padding)
+end
+
+---Raises an assertion error showing both values, `explain` adds where they differ.
+---@param assertion string
+---@param actual any
+---@param expected any
+---@param explain boolean
+local function fail(assertion, actual, expected, explain)
+	local lines = {
+		assertion .. " failed",
+		labeled("  actual", actual),
+		labeled("expected", expected),
+	}
+	local difference = explain and format.difference(actual, expected)
+	if difference then
+		table.insert(lines, "differs at:")
+		for line in difference:gmatch("[^\n]+") do
+			table.insert(lines, "  " .. line)
+		end
+	end
+	error(table.concat(lines, "\n"), 3)
+end
+
+---@param assertion string
+---@param value any
+local function fail_equal(assertion, value)
+	error(assertion .. " failed, both values are equal\n" .. labeled("  value", value), 3)
+end
+
+---Message of an error value. Errors of Rust callbacks are mlua userdata, their `tostring`
+---includes the whole `with_context` chain.
+---@param err any
+---@return string
+local function error_message(err)
+	if type(err) == "string" then
+		return err
+	end
+	if type(err) == "userdata" then
+		return tostring(err)
+	end
+	local metatable = getmetatable(err)
+	if type(metatable) == "table" and metatable.__tostring then
+		return tostring(err)
+	end
+	return format.show(err)
+end
+
+---@param message string
+---@return string
+local function indented(message)
+	return (message:gsub("\n", "\n         "))
+end
+
 ---@type lopa-test.module[]
 local modules = {}
 
 ---@type lopa-test.failed_case?
 local failed_case = nil
 
+---@class lopa-test.failure
+---@field case_name string?
+---@field error string
+
+---@class lopa-test.result
+---@field full_name string
+---@field passed boolean
+---@field timed_out boolean
+---@field failures lopa-test.failure[]
+
+---@return lopa-test.test[]
+local function collect_tests()
+	---@type lopa-test.test[]
+	local tests = {}
+	for _, mod in ipairs(modules) do
+		for _, test in ipairs(mod.tests) do
+			test.full_name = mod.name .. "." .. test.name
+			table.insert(tests, test)
+		end
+	end
+	return tests
+end
+
+---@param err any
+---@return boolean
+local function is_timeout(err)
+	---@type __inner
+	local inner = _G["__inner"]
+	local prefix = inner.lopa_test.timeout_prefix
+	return prefix ~= nil and tostring(err):find(prefix, 1, true) ~= nil
+end
+
+---Raised by `case` to abort the test after its case timed out, the case itself holds the error.
+local CASE_TIMED_OUT = {}
+
+---Keeps the traceback of timeouts, it shows where the test was stuck.
+---@param err any
+---@return any
+local function timeout_handler(err)
+	if is_timeout(err) then
+		return debug.traceback(tostring(err), 2)
+	end
+	return err
+end
+
+---@param test lopa-test.test
+---@return lopa-test.result
+local function run_test(test)
+	---@type __inner
+	local inner = _G["__inner"]
+	local limits = inner.lopa_test.set_limits ~= nil
+	if limits then
+		local timeout = test.timeout or inner.lopa_test.timeout
+		local instructions = test.instructions or inner.lopa_test.max_instructions
+		inner.lopa_test.set_limits(timeout, instructions)
+	end
+	local success, result = xpcall(test.fun, timeout_handler)
+	if limits then
+		inner.lopa_test.clear_limits()
+	end
+	spies.restore_all()
+
+	---@type lopa-test.failure[]
+	local failures = {}
+	local timed_out = false
+	if failed_case ~= nil then
+		timed_out = is_timeout(failed_case.error)
+		table.insert(failures, { case_name = failed_case.name, error = tostring(failed_case.error) })
+	end
+	if not success and result ~= CASE_TIMED_OUT then
+		timed_out = timed_out or is_timeout(result)
+		table.insert(failures, { error = tostring(result) })
+	end
+	failed_case = nil
+	return { full_name = test.full_name, passed = #failures == 0, timed_out = timed_out, failures = failures }
+end
+
+---@param amount integer
+local function print_header(amount)
+	local test_s = amount == 1 and "" or "s"
+	print("running " .. amount .. " test" .. test_s)
+	print()
+end
+
+---@param result lopa-test.result
+local function print_result(result)
+	local status = result.passed and "\x1b[32mok\x1b[0m"
+		or result.timed_out and "\x1b[31mTIMED OUT\x1b[0m"
+		or "\x1b[31mFAILED\x1b[0m"
+	print("test " .. result.full_name .. ": " .. status)
+end
+
+---@param results lopa-test.result[]
+---@param elapsed number
+local function print_summary(results, elapsed)
+	local success_amount, failed_amount = 0, 0
+	for _, result in ipairs(results) do
+		if result.passed then
+			success_amount = success_amount + 1
+		else
+			failed_amount = failed_amount + 1
+		end
+	end
+
+	if failed_amount > 0 then
+		print()
+		print("failures: ")
+		print()
+		for _, result in ipairs(results) do
+			for _, failure in ipairs(result.failures) do
+				local name = failure.case_name == nil and result.full_name
+					or string.format('%s, case: "%s"', result.full_name, failure.case_name)
+				print(string.format("---- %s ----", name))
+				print(failure.error)
+				print()
+			end
+		end
+	end
+	if #results > 0 then
+		print()
+	end
+
+	local test_result = failed_amount == 0 and "\x1b[32mok\x1b[0m" or "\x1b[31mFAILED\x1b[0m"
+	print(
+		string.format(
+			"test result: %s. %s passed; %s failed; finished in %.4f seconds",
+			test_result,
+			success_amount,
+			failed_amount,
+			elapsed
+		)
+	)
+end
+
 ---@class lopa-test.defs
 local M = {
+	---Registers the tests of a module. A test is either a function or a `lopa-test.limited_test`
+	---overriding the time and instruction limits, `limits` sets them for the whole module.
 	---@param name string
-	---@param test_fns table<string, fun()>
-	test_module = function(name, test_fns)
+	---@param test_fns table<string, fun()|lopa-test.limited_test>
+	---@param limits lopa-test.limits?
+	test_module = function(name, test_fns, limits)
+		limits = limits or {}
 		---@type lopa-test.test[]
 		local tests = {}
-		for name, fun in pairs(test_fns) do
-			table.insert(tests, { name = name, fun = fun })
+		for name, test in pairs(test_fns) do
+			if type(test) == "table" then
+				table.insert(tests, {
+					name = name,
+					fun = test.fun or test[1],
+					timeout = test.timeout or limits.timeout,
+					instructions = test.instructions or limits.instructions,
+				})
+			else
+				table.insert(tests, {
+					name = name,
+					fun = test,
+					timeout = limits.timeout,
+					instructions = limits.instructions,
+				})
+			end
 		end
+		table.sort(tests, function(a, b)
+			return a.name < b.name
+		end)
 		table.insert(modules, { name = name, tests = tests })
 	end,
 
@@ -92,22 +323,52 @@ local M = {
 	---@param name string
 	---@param case fun()
 	case = function(name, case)
-		local success, result = pcall(case)
+		local success, result = xpcall(case, timeout_handler)
 		if not success then
 			failed_case = {
 				name = name,
 				error = result,
 			}
+			if is_timeout(result) then
+				-- the limits only fire once, stop the whole test
+				error(CASE_TIMED_OUT, 0)
+			end
+		end
+	end,
+	---Seeded generators for `property`.
+	gen = property.gen,
+	---Checks that `fun` holds (does not error or return `false`) for generated arguments.
+	---A failing input is shrunk to a minimal one and reported together with the seed.
+	---@param name string
+	---@param generators lopa-test.generator[]
+	---@param fun fun(...): boolean?
+	---@param options lopa-test.property_options?
+	property = function(name, generators, fun, options)
+		local success, err = xpcall(function()
+			return property.check(name, generators, fun, options)
+		end, timeout_handler)
+		if not success and not is_timeout(err) then
+			error(err, 0)
+		end
+		if err ~= nil then
+			failed_case = {
+				name = name,
+				error = err,
+			}
+		end
+		if not success then
+			-- the limits only fire once, stop the whole test
+			error(CASE_TIMED_OUT, 0)
 		end
 	end,
 	assert_eq = function(a, b)
 		if a ~= b then
-			error("assert_eq failed")
+			fail("assert_eq", a, b, true)
 		end
 	end,
 	assert_neq = function(a, b)
 		if a == b then
-			error("assert_neq failed")
+			fail_equal("assert_neq", a)
 		end
 	end,
 	assert_eq_shallow = function(a, b)
@@ -116,24 +377,20 @@ local M = {
 		end
 		local type_a = type(a)
 		if type_a ~= "table" then
-			error("assert_eq_shallow failed")
+			fail("assert_eq_shallow", a, b, false)
 		end
 
 		for a_key, a_value in pairs(a) do
 			for b_key, b_value in pairs(b) do
-				if a_key == b_key and a_value == b_value then
-					goto continue
-				else
-					--TODO: print whats wrong as well as keys/values
-					error("assert_eq_shallow failed")
+				if not (a_key == b_key and a_value == b_value) then
+					fail("assert_eq_shallow", a, b, false)
 				end
-				::continue::
 			end
 		end
 	end,
 	assert_eq_deep = function(a, b)
 		if not deep_equals(a, b) then
-			error("assert_eq_deep failed")
+			fail("assert_eq_deep", a, b, true)
 		end
 	end,
 	assert_neq_shallow = function(a, b)
@@ -143,105 +400,158 @@ local M = {
 
 		local type_a = type(a)
 		if type_a ~= "table" then
-			error("assert_neq_shallow failed")
+			fail_equal("assert_neq_shallow", a)
 		end
 
 		for a_key, a_value in pairs(a) do
 			for b_key, b_value in pairs(b) do
-				if a_key ~= b_key and a_value ~= b_value then
-					goto continue
-				else
-					--TODO: print whats wrong as well as keys/values
-					error("assert_neq_shallow failed")
+				if not (a_key ~= b_key and a_value ~= b_value) then
+					fail_equal("assert_neq_shallow", a)
 				end
-				::continue::
 			end
 		end
 	end,
 	assert_neq_deep = function(a, b)
-		if not deep_equals(a, b) then
-			error("assert_neq_deep failed")
+		if deep_equals(a, b) then
+			fail_equal("assert_neq_deep", a)
 		end
 	end,
-	---@private
-	execute_tests = function()
-		---@type lopa-test.test[]
-		local tests = {}
-		for _, mod in pairs(modules) do
-			for _, test in pairs(mod.tests) do
-				test.full_name = mod.name .. "." .. test.name
-				table.insert(tests, test)
+	---Asserts that `fun` raises an error whose message matches the Lua `pattern`, if given.
+	---Returns the error message.
+	---@param fun fun()
+	---@param pattern string?
+	---@return string
+	assert_error = function(fun, pattern)
+		local success, err = pcall(fun)
+		if success then
+			error("assert_error failed, no error was raised", 2)
+		end
+		if is_timeout(err) then
+			error(err, 0)
+		end
+		local message = error_message(err)
+		if pattern ~= nil and not message:find(pattern) then
+			error(
+				string.format(
+					"assert_error failed, the error does not match `%s`\n  error: %s",
+					pattern,
+					indented(message)
+				),
+				2
+			)
+		end
+		return message
+	end,
+	---Asserts that `fun` runs without raising an error.
+	---@param fun fun()
+	assert_no_error = function(fun)
+		local success, err = pcall(fun)
+		if not success then
+			if is_timeout(err) then
+				error(err, 0)
 			end
+			error("assert_no_error failed, an error was raised\n  error: " .. indented(error_message(err)), 2)
 		end
-		local test_s = #tests == 1 and "" or "s"
-		print("running " .. #tests .. " test" .. test_s)
-		print()
-
-		---@class lopa-test.failed-test
-		---@field full_name string
-		---@field error any?
-		---@field case_name string?
-
-		---@type lopa-test.failed-test[]
-		local failed_tests = {}
-		local success_amount = 0
+	end,
+	---Wraps `fun` (if given) into a callable spy recording every call and its arguments.
+	spy = spies.spy,
+	---Replaces `t[key]` with a spy forwarding to `impl`, the original is restored when the test ends.
+	mock = spies.mock,
+	---Asserts that `spy` was called, exactly `times` times if given.
+	---@param spy lopa-test.spy
+	---@param times integer?
+	assert_called = function(spy, times)
+		local count = spy:call_count()
+		if (times == nil and count == 0) or (times ~= nil and count ~= times) then
+			error(
+				string.format(
+					"assert_called failed, expected %s call(s), got %d\n  calls: %s",
+					times or "some",
+					count,
+					indented(spy:describe_calls())
+				),
+				2
+			)
+		end
+	end,
+	---@param spy lopa-test.spy
+	assert_not_called = function(spy)
+		if spy:call_count() > 0 then
+			error("assert_not_called failed\n  calls: " .. indented(spy:describe_calls()), 2)
+		end
+	end,
+	---Asserts that one of the calls of `spy` got these arguments, meth values are compared
+	---by their components.
+	---@param spy lopa-test.spy
+	assert_called_with = function(spy, ...)
+		if not spy:called_with(...) then
+			local args = {}
+			for i = 1, select("#", ...) do
+				args[i] = format.show((select(i, ...)))
+			end
+			error(
+				string.format(
+					"assert_called_with failed, no call with (%s)\n  calls: %s",
+					table.concat(args, ", "),
+					indented(spy:describe_calls())
+				),
+				2
+			)
+		end
+	end,
+	---Compares a pretty-printed `value` with the snapshot `name` stored next to the test module.
+	---The snapshot is written on the first run and rewritten when running with `--update-snapshots`.
+	---@param name string
+	---@param value any
+	assert_snapshot = function(name, value)
+		snapshot.assert_snapshot(name, value, 2)
+	end,
+	---Runs every registered test without printing anything.
+	---@return lopa-test.result[]
+	run_tests = function()
+		local results = {}
+		for _, test in ipairs(collect_tests()) do
+			table.insert(results, run_test(test))
+		end
+		return results
+	end,
+	---Prints the results of `run_tests`, possibly collected from several Lua states.
+	---@param results lopa-test.result[]
+	---@param elapsed number seconds
+	report = function(results, elapsed)
+		print_header(#results)
+		for _, result in ipairs(results) do
+			print_result(result)
+		end
+		print_summary(results, elapsed)
+	end,
+	---Runs and prints every registered test, returns whether all of them passed.
+	---@private
+	---@return boolean
+	execute_tests = function()
+		local tests = collect_tests()
+		print_header(#tests)
 
 		---@type __inner
 		local inner = _G["__inner"]
 
+		---@type lopa-test.result[]
+		local results = {}
 		inner.lopa_test.start_timer()
-		for _, test in pairs(tests) do
-			local success, result = pcall(test.fun)
-			local failed = not success or failed_case ~= nil
-			if failed then
-				print("test " .. test.full_name .. ": " .. "\x1b[31mFAILED\x1b[0m")
-				if failed_case ~= nil then
-					table.insert(failed_tests, {
-						full_name = test.full_name,
-						case_name = failed_case.name,
-						error = failed_case.error,
-					})
-				end
-				if result ~= nil then
-					table.insert(failed_tests, {
-						full_name = test.full_name,
-						error = result,
-					})
-				end
-			else
-				print("test " .. test.full_name .. ": " .. "\x1b[32mok\x1b[0m")
-				success_amount = success_amount + 1
-			end
-			failed_case = nil
+		for _, test in ipairs(tests) do
+			local result = run_test(test)
+			print_result(result)
+			table.insert(results, result)
 		end
 		local diff = inner.lopa_test.end_timer()
 
-		if #failed_tests > 0 then
-			print()
-			print("failures: ")
-			print()
-			for _, test in ipairs(failed_tests) do
-				local name = test.case_name == nil and test.full_name
-					or string.format('%s, case: "%s"', test.full_name, test.case_name)
-				print(string.format("---- %s ----", name))
-				print(test.error)
-				print()
+		print_summary(results, diff)
+		for _, result in ipairs(results) do
+			if not result.passed then
+				return false
 			end
 		end
-		if #tests > 0 then
-			print()
-		end
-
-		local test_result = #failed_tests == 0 and "\x1b[32mok\x1b[0m" or "\x1b[31mFAILED\x1b[0m"
-		print(
-			string.format(
-				"test result: %s. %s passed; %s failed; finished in %.4f seconds",
-				test_result,
-				success_amount,
-				#failed_tests,
-				diff
-			)
-		)
+		return true
 	end,
 }
 return M
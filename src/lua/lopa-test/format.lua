@@ -0,0 +1,292 @@
+local inspect = require("lopa-test.inspect")
+
+---Returns the meth type name (`"Vec3"`, `"IVec2"`, `"Mat4"`, ...) of a value, or `nil` for anything else.
+---@param value any
+---@return string?
+local function meth_type(value)
+	-- Vec3 is a native `vector` on Luau
+	local kind = type(value)
+	if (kind ~= "table" and kind ~= "vector") or getmetatable(value) == nil then
+		return nil
+	end
+	local t = value.type
+	if type(t) ~= "string" then
+		return nil
+	end
+	if t:match("^I?Vec[234]$") or t:match("^Mat[234]$") then
+		return t
+	end
+	return nil
+end
+
+---@param value string|number|boolean
+---@return string
+local function format_key(value)
+	if type(value) == "string" and value:match("^[_%a][_%w]*$") then
+		return value
+	end
+	return "[" .. inspect(value) .. "]"
+end
+
+---@param a any
+---@param b any
+---@return boolean
+local function sort_keys(a, b)
+	local type_a, type_b = type(a), type(b)
+	if type_a ~= type_b then
+		return type_a < type_b
+	end
+	if type_a == "number" or type_a == "string" then
+		return a < b
+	end
+	return tostring(a) < tostring(b)
+end
+
+---@param t table
+---@return integer, any[]
+local function table_keys(t)
+	local sequence_length = #t
+	local keys = {}
+	for key in pairs(t) do
+		if type(key) ~= "number" or key < 1 or key > sequence_length or key % 1 ~= 0 then
+			table.insert(keys, key)
+		end
+	end
+	table.sort(keys, sort_keys)
+	return sequence_length, keys
+end
+
+local pretty
+
+---Integral floats print as `2.0` on Lua 5.4 but as `2` on LuaJIT, snapshots use the latter.
+---@param value number
+---@return string
+local function format_number(value)
+	if value == math.floor(value) and math.abs(value) < 2 ^ 53 then
+		return string.format("%d", value)
+	end
+	return tostring(value)
+end
+
+---@param value table
+---@param t string
+---@param indent string
+---@return string
+local function pretty_meth(value, t, indent)
+	local size = tonumber(t:sub(-1))
+	if t:sub(1, 3) ~= "Mat" then
+		local components = {}
+		for i = 1, size do
+			components[i] = format_number(value[i])
+		end
+		return t .. "(" .. table.concat(components, ", ") .. ")"
+	end
+
+	local columns = {}
+	for column = 0, size - 1 do
+		local components = {}
+		for row = 1, size do
+			components[row] = format_number(value[column * size + row])
+		end
+		columns[column + 1] = indent .. "\t" .. table.concat(components, ", ")
+	end
+	return t .. "(\n" .. table.concat(columns, ",\n") .. "\n" .. indent .. ")"
+end
+
+---@param value any
+---@param indent string
+---@param visited table<table, boolean>
+---@return string
+function pretty(value, indent, visited)
+	local t = meth_type(value)
+	if t then
+		return pretty_meth(value, t, indent)
+	end
+	if type(value) ~= "table" then
+		return inspect(value)
+	end
+	if visited[value] then
+		return "<cycle>"
+	end
+
+	local sequence_length, keys = table_keys(value)
+	if sequence_length == 0 and #keys == 0 then
+		return "{}"
+	end
+
+	visited[value] = true
+	local inner_indent = indent .. "\t"
+	local lines = {}
+	for i = 1, sequence_length do
+		table.insert(lines, inner_indent .. pretty(value[i], inner_indent, visited))
+	end
+	for _, key in ipairs(keys) do
+		table.insert(lines, inner_indent .. format_key(key) .. " = " .. pretty(value[key], inner_indent, visited))
+	end
+	visited[value] = nil
+
+	return "{\n" .. table.concat(lines, ",\n") .. "\n" .. indent .. "}"
+end
+
+local VECTOR_COMPONENTS = { "x", "y", "z", "w" }
+
+---Lua field name of the `i`th component, `x`..`w` for vectors and `x1`..`w4` for matrices.
+---@param t string
+---@param i integer
+---@return string
+local function component_name(t, i)
+	if t:sub(1, 3) ~= "Mat" then
+		return VECTOR_COMPONENTS[i]
+	end
+	local size = tonumber(t:sub(-1))
+	return VECTOR_COMPONENTS[(i - 1) % size + 1] .. tostring(math.floor((i - 1) / size) + 1)
+end
+
+---@param value any
+---@return string
+local function show(value)
+	if meth_type(value) then
+		return tostring(value)
+	end
+	return inspect(value)
+end
+
+---@param path string
+---@param key any
+---@return string
+local function join_path(path, key)
+	if type(key) == "string" and key:match("^[_%a][_%w]*$") then
+		return path == "" and key or path .. "." .. key
+	end
+	return path .. "[" .. inspect(key) .. "]"
+end
+
+---@param a any
+---@param b any
+---@param path string
+---@return string?
+local function first_difference(a, b, path)
+	local type_a, type_b = meth_type(a), meth_type(b)
+	if type_a and type_b then
+		if type_a ~= type_b then
+			return string.format("%s: %s ~= %s", path == "" and "type" or path .. ".type", type_a, type_b)
+		end
+		local size = tonumber(type_a:sub(-1))
+		local count = type_a:sub(1, 3) == "Mat" and size * size or size
+		local components = {}
+		for i = 1, count do
+			if a[i] ~= b[i] then
+				table.insert(
+					components,
+					string.format("%s: %s ~= %s", join_path(path, component_name(type_a, i)), a[i], b[i])
+				)
+			end
+		end
+		return #components > 0 and table.concat(components, "\n") or nil
+	end
+
+	if type(a) ~= "table" or type(b) ~= "table" or type_a or type_b then
+		if a == b then
+			return nil
+		end
+		return string.format("%s: %s ~= %s", path == "" and "value" or path, show(a), show(b))
+	end
+
+	local _, keys = table_keys(a)
+	for i = 1, #a do
+		table.insert(keys, i)
+	end
+	table.sort(keys, sort_keys)
+	for _, key in ipairs(keys) do
+		if b[key] == nil then
+			return string.format("%s: missing on the right", join_path(path, key))
+		end
+		local difference = first_difference(a[key], b[key], join_path(path, key))
+		if difference then
+			return difference
+		end
+	end
+	for key in pairs(b) do
+		if a[key] == nil then
+			return string.format("%s: missing on the left", join_path(path, key))
+		end
+	end
+	return nil
+end
+
+---@param text string
+---@return string[]
+local function split_lines(text)
+	local lines = {}
+	for line in (text .. "\n"):gmatch("(.-)\n") do
+		table.insert(lines, line)
+	end
+	return lines
+end
+
+---@class lopa-test.format
+local M = {
+	meth_type = meth_type,
+
+	---Multi-line, deterministic representation of a value. Meth vectors and matrices are printed
+	---with their components, tables with sorted keys and one entry per line.
+	---@param value any
+	---@return string
+	pretty = function(value)
+		return pretty(value, "", {})
+	end,
+
+	---Single value representation: meth values use their `__tostring`, everything else `inspect`.
+	show = show,
+
+	---Describes where `a` and `b` differ: every differing component for meth values, the path
+	---to the first difference for nested tables. Returns `nil` if no difference was found.
+	---@param a any
+	---@param b any
+	---@return string?
+	difference = function(a, b)
+		return first_difference(a, b, "")
+	end,
+
+	---Line based diff of two strings, `-` lines are only in `old`, `+` lines are only in `new`.
+	---@param old string
+	---@param new string
+	---@return string
+	diff_lines = function(old, new)
+		local a, b = split_lines(old), split_lines(new)
+		local n, m = #a, #b
+
+		-- longest common subsequence lengths of suffixes
+		local lcs = {}
+		for i = n + 1, 1, -1 do
+			lcs[i] = {}
+			for j = m + 1, 1, -1 do
+				if i > n or j > m then
+					lcs[i][j] = 0
+				elseif a[i] == b[j] then
+					lcs[i][j] = lcs[i + 1][j + 1] + 1
+				else
+					lcs[i][j] = math.max(lcs[i + 1][j], lcs[i][j + 1])
+				end
+			end
+		end
+
+		local out = {}
+		local i, j = 1, 1
+		while i <= n or j <= m do
+			if i <= n and j <= m and a[i] == b[j] then
+				table.insert(out, "  " .. a[i])
+				i, j = i + 1, j + 1
+			elseif j <= m and (i > n or lcs[i][j + 1] >= lcs[i + 1][j]) then
+				table.insert(out, "\x1b[32m+ " .. b[j] .. "\x1b[0m")
+				j = j + 1
+			else
+				table.insert(out, "\x1b[31m- " .. a[i] .. "\x1b[0m")
+				i = i + 1
+			end
+		end
+		return table.concat(out, "\n")
+	end,
+}
+
+return M
@@ -4,6 +4,17 @@
 ---@field start_timer fun()
 ---@field end_timer fun():float
 ---@field start userdata?
+---@field update_snapshots boolean
+---@field create_dir_all fun(path: string)
+---@field read_file fun(path: string): string?
+---@field write_file fun(path: string, contents: string)
+---@field property_seed integer?
+---@field timeout number?
+---@field max_instructions integer?
+---@field timeout_prefix string?
+---@field set_limits fun(timeout: number?, instructions: integer?)?
+---@field clear_limits fun()?
 
 ---@class __inner
 ---@field lopa_test __inner.lopa-test
+---@field meth_conversions table<string, fun(value: any): any>
@@ -0,0 +1,315 @@
+local format = require("lopa-test.format")
+
+local unpack = table.unpack or unpack
+
+local DEFAULT_RUNS = 100
+local MAX_SHRINKS = 1000
+local MODULUS = 2147483647
+
+---Park-Miller generator, all intermediate values stay exact in doubles so every Lua version
+---produces the same sequence for the same seed.
+---@class (exact) lopa-test.rng
+---@field state integer
+local Rng = {}
+Rng.__index = Rng
+
+---@param seed integer
+---@return lopa-test.rng
+function Rng.new(seed)
+	return setmetatable({ state = seed % (MODULUS - 1) + 1 }, Rng)
+end
+
+---@return number in [0, 1)
+function Rng:next()
+	self.state = self.state * 16807 % MODULUS
+	return (self.state - 1) / (MODULUS - 1)
+end
+
+---@param min number
+---@param max number
+---@return number
+function Rng:number(min, max)
+	return min + (max - min) * self:next()
+end
+
+---@param min integer
+---@param max integer
+---@return integer
+function Rng:integer(min, max)
+	return math.min(min + math.floor((max - min + 1) * self:next()), max)
+end
+
+---@class (exact) lopa-test.generator
+---@field generate fun(rng: lopa-test.rng): any
+---@field shrink fun(raw: any): any[]
+---@field build fun(raw: any): any
+
+---@param min number
+---@param max number
+---@return number
+local function origin(min, max)
+	return math.max(math.min(0, max), min)
+end
+
+---@param min number?
+---@param max number?
+---@return lopa-test.generator
+local function number(min, max)
+	min, max = min or -100, max or 100
+	local target = origin(min, max)
+	return {
+		generate = function(rng)
+			return rng:number(min, max)
+		end,
+		shrink = function(raw)
+			if raw == target then
+				return {}
+			end
+			local candidates = { target }
+			local truncated = raw >= 0 and math.floor(raw) or math.ceil(raw)
+			if truncated ~= raw and truncated >= min and truncated <= max then
+				table.insert(candidates, truncated)
+			end
+			table.insert(candidates, target + (raw - target) / 2)
+			return candidates
+		end,
+		build = function(raw)
+			return raw
+		end,
+	}
+end
+
+---@param min integer?
+---@param max integer?
+---@return lopa-test.generator
+local function integer(min, max)
+	min, max = min or -100, max or 100
+	local target = origin(min, max)
+	return {
+		generate = function(rng)
+			return rng:integer(min, max)
+		end,
+		shrink = function(raw)
+			if raw == target then
+				return {}
+			end
+			local candidates = { target }
+			local half = target + (raw - target) / 2
+			half = half >= 0 and math.floor(half) or math.ceil(half)
+			if half ~= target and half ~= raw then
+				table.insert(candidates, half)
+			end
+			local step = raw > target and raw - 1 or raw + 1
+			if step ~= half and step ~= target then
+				table.insert(candidates, step)
+			end
+			return candidates
+		end,
+		build = function(raw)
+			return raw
+		end,
+	}
+end
+
+---Generator for values made of `size` components, shrinking one component at a time.
+---@param size integer
+---@param component lopa-test.generator
+---@param constructor fun(...): any
+---@return lopa-test.generator
+local function composite(size, component, constructor)
+	return {
+		generate = function(rng)
+			local raw = {}
+			for i = 1, size do
+				raw[i] = component.generate(rng)
+			end
+			return raw
+		end,
+		shrink = function(raw)
+			local candidates = {}
+			for i = 1, size do
+				for _, shrunk in ipairs(component.shrink(raw[i])) do
+					local candidate = { unpack(raw) }
+					candidate[i] = shrunk
+					table.insert(candidates, candidate)
+				end
+			end
+			return candidates
+		end,
+		build = function(raw)
+			return constructor(unpack(raw))
+		end,
+	}
+end
+
+---@class lopa-test.generators
+local gen = {
+	number = number,
+	integer = integer,
+	---@param min number?
+	---@param max number?
+	vec2 = function(min, max)
+		return composite(2, number(min, max), vec2)
+	end,
+	---@param min number?
+	---@param max number?
+	vec3 = function(min, max)
+		return composite(3, number(min, max), vec3)
+	end,
+	---@param min number?
+	---@param max number?
+	vec4 = function(min, max)
+		return composite(4, number(min, max), vec4)
+	end,
+	---@param min integer?
+	---@param max integer?
+	ivec2 = function(min, max)
+		return composite(2, integer(min, max), ivec2)
+	end,
+	---@param min integer?
+	---@param max integer?
+	ivec3 = function(min, max)
+		return composite(3, integer(min, max), ivec3)
+	end,
+	---@param min integer?
+	---@param max integer?
+	ivec4 = function(min, max)
+		return composite(4, integer(min, max), ivec4)
+	end,
+	---@param min number?
+	---@param max number?
+	mat2 = function(min, max)
+		return composite(4, number(min, max), mat2)
+	end,
+	---@param min number?
+	---@param max number?
+	mat3 = function(min, max)
+		return composite(9, number(min, max), mat3)
+	end,
+	---@param min number?
+	---@param max number?
+	mat4 = function(min, max)
+		return composite(16, number(min, max), mat4)
+	end,
+}
+
+---@param generators lopa-test.generator[]
+---@param raws any[]
+---@return any[]
+local function build_all(generators, raws)
+	local values = {}
+	for i, generator in ipairs(generators) do
+		values[i] = generator.build(raws[i])
+	end
+	return values
+end
+
+---@param err any
+---@return boolean
+local function is_timeout(err)
+	---@type __inner
+	local inner = _G["__inner"]
+	local prefix = inner.lopa_test.timeout_prefix
+	return prefix ~= nil and tostring(err):find(prefix, 1, true) ~= nil
+end
+
+---Timeouts are rethrown instead of treated as a failing input, the limits only fire once so
+---shrinking a looping property would never end.
+---@param fun fun(...): boolean?
+---@param generators lopa-test.generator[]
+---@param raws any[]
+---@return any? error `nil` when the property holds
+local function run(fun, generators, raws)
+	local values = build_all(generators, raws)
+	local success, result = pcall(fun, unpack(values, 1, #generators))
+	if not success then
+		if is_timeout(result) then
+			error(result, 0)
+		end
+		return result
+	end
+	if result == false then
+		return "property returned false"
+	end
+	return nil
+end
+
+---@param fun fun(...): boolean?
+---@param generators lopa-test.generator[]
+---@param raws any[]
+---@param err any
+---@return any[] raws, any err, integer shrinks
+local function shrink(fun, generators, raws, err)
+	local shrinks = 0
+	local progress = true
+	while progress and shrinks < MAX_SHRINKS do
+		progress = false
+		for i, generator in ipairs(generators) do
+			for _, candidate in ipairs(generator.shrink(raws[i])) do
+				local candidate_raws = { unpack(raws, 1, #generators) }
+				candidate_raws[i] = candidate
+				local candidate_err = run(fun, generators, candidate_raws)
+				if candidate_err ~= nil then
+					raws, err = candidate_raws, candidate_err
+					shrinks = shrinks + 1
+					progress = true
+					break
+				end
+			end
+			if progress then
+				break
+			end
+		end
+	end
+	return raws, err, shrinks
+end
+
+---@class (exact) lopa-test.property_options
+---@field runs integer?
+---@field seed integer?
+
+---@class lopa-test.property
+local M = {
+	gen = gen,
+
+	---Checks `fun` against `runs` sets of generated arguments. Returns a description of the
+	---minimal failing input, or `nil` if the property holds. Timeouts are raised as errors.
+	---@param name string
+	---@param generators lopa-test.generator[]
+	---@param fun fun(...): boolean?
+	---@param options lopa-test.property_options?
+	---@return string?
+	check = function(name, generators, fun, options)
+		options = options or {}
+		---@type __inner
+		local inner = _G["__inner"]
+		local seed = options.seed or inner.lopa_test.property_seed or os.time()
+		local runs = options.runs or DEFAULT_RUNS
+		local rng = Rng.new(seed)
+
+		for i = 1, runs do
+			local raws = {}
+			for j, generator in ipairs(generators) do
+				raws[j] = generator.generate(rng)
+			end
+			local err = run(fun, generators, raws)
+			if err ~= nil then
+				local shrinks
+				raws, err, shrinks = shrink(fun, generators, raws, err)
+				local lines = {
+					string.format("property `%s` failed after %d run(s) (seed: %d)", name, i, seed),
+					string.format("minimal failing input (shrunk %d time(s)):", shrinks),
+				}
+				for j, value in ipairs(build_all(generators, raws)) do
+					table.insert(lines, string.format("  %d: %s", j, format.pretty(value)))
+				end
+				table.insert(lines, "error: " .. tostring(err))
+				table.insert(lines, string.format("rerun with --seed %d to reproduce", seed))
+				return table.concat(lines, "\n")
+			end
+		end
+		return nil
+	end,
+}
+
+return M
@@ -0,0 +1,55 @@
+local format = require("lopa-test.format")
+
+---Snapshots of a test module live in `__snapshots__` next to it, one file per snapshot.
+---@param source string chunk source of the test module, as returned by `debug.getinfo` or `debug.info`
+---@param name string
+---@return string dir, string path
+local function snapshot_path(source, name)
+	local file = source:sub(1, 1) == "@" and source:sub(2) or "unknown"
+	local dir, stem = file:match("^(.-)([^/\\]-)%.lua$")
+	if dir == nil then
+		dir, stem = "", file:gsub("[^%w_%-]", "_")
+	end
+	dir = dir .. "__snapshots__"
+	local file_name = stem .. "." .. name:gsub("[^%w_%-%.]", "_") .. ".snap"
+	return dir, dir .. "/" .. file_name
+end
+
+---@class lopa-test.snapshot
+local M = {
+	---@param name string
+	---@param value any
+	---@param level integer stack level of the test function calling the assertion
+	assert_snapshot = function(name, value, level)
+		---@type __inner
+		local inner = _G["__inner"]
+		-- Luau has no `debug.getinfo`
+		local source = debug.getinfo ~= nil and debug.getinfo(level + 1, "S").source or debug.info(level + 1, "s")
+		local dir, path = snapshot_path(source, name)
+		local actual = format.pretty(value) .. "\n"
+
+		-- file access goes through Rust, Luau has no `io` library
+		local expected = inner.lopa_test.read_file(path)
+		if expected == nil or inner.lopa_test.update_snapshots then
+			if expected ~= actual then
+				inner.lopa_test.create_dir_all(dir)
+				inner.lopa_test.write_file(path, actual)
+			end
+			return
+		end
+
+		if expected ~= actual then
+			error(
+				string.format(
+					"snapshot `%s` does not match (%s)\n%s\n\nrun with --update-snapshots to accept the new value",
+					name,
+					path,
+					format.diff_lines(expected, actual)
+				),
+				level + 1
+			)
+		end
+	end,
+}
+
+return M
@@ -0,0 +1,130 @@
+local format = require("lopa-test.format")
+
+local unpack = table.unpack or unpack
+
+---@class (exact) lopa-test.call
+---@field n integer
+---@field [integer] any
+
+---A callable that records its calls before forwarding them to the wrapped function.
+---@class (exact) lopa-test.spy
+---@field calls lopa-test.call[]
+---@field fun function?
+local Spy = {}
+Spy.__index = Spy
+
+Spy.__call = function(self, ...)
+	table.insert(self.calls, { n = select("#", ...), ... })
+	if self.fun ~= nil then
+		return self.fun(...)
+	end
+end
+
+---Values are equal if `format.difference` finds nothing, so meth values are compared by component.
+---@param a any
+---@param b any
+---@return boolean
+local function equals(a, b)
+	return format.difference(a, b) == nil
+end
+
+---@param call lopa-test.call
+---@param args lopa-test.call
+---@return boolean
+local function matches(call, args)
+	if call.n ~= args.n then
+		return false
+	end
+	for i = 1, call.n do
+		if not equals(call[i], args[i]) then
+			return false
+		end
+	end
+	return true
+end
+
+---@return integer
+function Spy:call_count()
+	return #self.calls
+end
+
+---@return boolean
+function Spy:called_with(...)
+	local args = { n = select("#", ...), ... }
+	for _, call in ipairs(self.calls) do
+		if matches(call, args) then
+			return true
+		end
+	end
+	return false
+end
+
+---@return string
+function Spy:describe_calls()
+	if #self.calls == 0 then
+		return "no calls"
+	end
+	local lines = {}
+	for i, call in ipairs(self.calls) do
+		local args = {}
+		for j = 1, call.n do
+			args[j] = format.show(call[j])
+		end
+		table.insert(lines, string.format("%d: (%s)", i, table.concat(args, ", ")))
+	end
+	return table.concat(lines, "\n")
+end
+
+---@param value any
+---@return boolean
+local function is_spy(value)
+	return getmetatable(value) == Spy
+end
+
+---@class (exact) lopa-test.mocked
+---@field table table
+---@field key any
+---@field original any
+
+---@type lopa-test.mocked[]
+local mocked = {}
+
+---@class lopa-test.spies
+local M = {
+	is_spy = is_spy,
+
+	---@param fun function?
+	---@return lopa-test.spy
+	spy = function(fun)
+		return setmetatable({ calls = {}, fun = fun }, Spy)
+	end,
+
+	---Replaces `t[key]` with a spy forwarding to `impl`, until `restore_all` is called.
+	---@param t table
+	---@param key any
+	---@param impl function?
+	---@return lopa-test.spy
+	mock = function(t, key, impl)
+		local spy = setmetatable({ calls = {}, fun = impl }, Spy)
+		table.insert(mocked, { table = t, key = key, original = t[key] })
+		t[key] = spy
+		return spy
+	end,
+
+	---Puts every mocked value back, most recent mock first.
+	restore_all = function()
+		for i = #mocked, 1, -1 do
+			local mock = mocked[i]
+			mock.table[mock.key] = mock.original
+			mocked[i] = nil
+		end
+	end,
+
+	---@param call lopa-test.call
+	---@return any ...
+	unpack_call = function(call)
+		return unpack(call, 1, call.n)
+	end,
+}
+
+return M
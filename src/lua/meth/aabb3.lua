@@ -0,0 +1,200 @@
+---@class meth.Aabb3
+---@field min meth.Vec3
+---@field max meth.Vec3
+
+---@param self meth.Aabb3
+---@return meth.Vec3
+local function size(self)
+	return self[2] - self[1]
+end
+
+---@class meth.Aabb3
+local methods = {
+	---@param self meth.Aabb3
+	---@return meth.Vec3, meth.Vec3
+	unpack = function(self)
+		return self[1], self[2]
+	end,
+	---@param self meth.Aabb3
+	---@param rhs meth.Aabb3
+	---@return meth.Aabb3
+	assign_from = function(self, rhs)
+		self[1] = rhs[1]:copy()
+		self[2] = rhs[2]:copy()
+		return self
+	end,
+	---@param self meth.Aabb3
+	---@return meth.Aabb3
+	copy = function(self)
+		return aabb3(self[1]:copy(), self[2]:copy())
+	end,
+	---@param self meth.Aabb3
+	---@return meth.Vec3
+	center = function(self)
+		return (self[1] + self[2]) * 0.5
+	end,
+	size = size,
+	---@param self meth.Aabb3
+	---@return number
+	area = function(self)
+		local size = size(self)
+		return 2 * (size[1] * size[2] + size[2] * size[3] + size[3] * size[1])
+	end,
+	---@param self meth.Aabb3
+	---@return number
+	volume = function(self)
+		local size = size(self)
+		return size[1] * size[2] * size[3]
+	end,
+	---@param self meth.Aabb3
+	---@param point meth.Vec3
+	---@return boolean
+	contains = function(self, point)
+		for i = 1, 3 do
+			if point[i] < self[1][i] or self[2][i] < point[i] then
+				return false
+			end
+		end
+		return true
+	end,
+	---@param self meth.Aabb3
+	---@param other meth.Aabb3
+	---@return boolean
+	intersects = function(self, other)
+		for i = 1, 3 do
+			if other[2][i] < self[1][i] or self[2][i] < other[1][i] then
+				return false
+			end
+		end
+		return true
+	end,
+	---@param self meth.Aabb3
+	---@param other meth.Aabb3
+	---@return meth.Aabb3?
+	intersection = function(self, other)
+		if not self:intersects(other) then
+			return nil
+		end
+		return aabb3(self[1]:maxed(other[1]), self[2]:mined(other[2]))
+	end,
+	---@param self meth.Aabb3
+	---@param other meth.Aabb3
+	---@return meth.Aabb3
+	union = function(self, other)
+		self[1] = self[1]:mined(other[1])
+		self[2] = self[2]:maxed(other[2])
+		return self
+	end,
+	---@param self meth.Aabb3
+	---@param other meth.Aabb3
+	---@return meth.Aabb3
+	unioned = function(self, other)
+		return aabb3(self[1]:mined(other[1]), self[2]:maxed(other[2]))
+	end,
+	---@param self meth.Aabb3
+	---@param amount number
+	---@return meth.Aabb3
+	expand = function(self, amount)
+		self[1] = self[1] - amount
+		self[2] = self[2] + amount
+		return self
+	end,
+	---@param self meth.Aabb3
+	---@param amount number
+	---@return meth.Aabb3
+	expanded = function(self, amount)
+		return aabb3(self[1] - amount, self[2] + amount)
+	end,
+	---@param self meth.Aabb3
+	---@param offset meth.Vec3
+	---@return meth.Aabb3
+	translate = function(self, offset)
+		self[1] = self[1] + offset
+		self[2] = self[2] + offset
+		return self
+	end,
+	---@param self meth.Aabb3
+	---@param offset meth.Vec3
+	---@return meth.Aabb3
+	translated = function(self, offset)
+		return aabb3(self[1] + offset, self[2] + offset)
+	end,
+	---@param self meth.Aabb3
+	---@param point meth.Vec3
+	---@return meth.Vec3
+	clamp_point = function(self, point)
+		return point:maxed(self[1]):mined(self[2])
+	end,
+	type = "Aabb3",
+}
+
+---@type metatable
+local metatable = {
+	__newindex = function(self, key, value)
+		if key == "min" then
+			rawset(self, 1, value)
+		elseif key == "max" then
+			rawset(self, 2, value)
+		end
+	end,
+	---@param self meth.Aabb3
+	__index = function(self, value)
+		if value == "min" then
+			return self[1]
+		elseif value == "max" then
+			return self[2]
+		else
+			return methods[value]
+		end
+	end,
+	__eq = function(a, b)
+		return a[1] == b[1] and a[2] == b[2]
+	end,
+	__len = function()
+		return 2
+	end,
+	__tostring = function(self)
+		return "[" .. tostring(self[1]) .. ", " .. tostring(self[2]) .. "]"
+	end,
+}
+
+---@return meth.Aabb3
+---@overload fun(min: meth.Vec3, max: meth.Vec3): meth.Aabb3
+---@overload fun(min_x: number, min_y: number, min_z: number, max_x: number, max_y: number, max_z: number): meth.Aabb3
+---@overload fun(): meth.Aabb3
+---@diagnostic disable-next-line: lowercase-global
+function aabb3(min_x, min_y, min_z, max_x, max_y, max_z)
+	if type(min_x) == "number" then
+		return setmetatable({ vec3(min_x, min_y, min_z), vec3(max_x, max_y, max_z) }, metatable)
+	elseif min_x ~= nil then
+		return setmetatable({ min_x:copy(), min_y:copy() }, metatable)
+	else
+		return setmetatable({ vec3(), vec3() }, metatable)
+	end
+end
+
+local inner = _G["__inner"]
+---@type __inner
+local inner = inner ~= nil and inner or {}
+_G["__inner"] = inner
+inner.meth.aabb3_metatable = metatable
+inner.meth.aabb3_methods = methods
+
+---@class meth.Aabb3.definitions
+local Aabb3 = {
+	---@param min meth.Vec3
+	---@param max meth.Vec3
+	---@return meth.Aabb3
+	from_min_max = function(min, max)
+		return aabb3(min, max)
+	end,
+	---@param center meth.Vec3
+	---@param size meth.Vec3
+	---@return meth.Aabb3
+	from_center_size = function(center, size)
+		local min = center - size * 0.5
+		return aabb3(min, min + size)
+	end,
+}
+
+return Aabb3
@@ -0,0 +1,89 @@
+---@class meth.Circle
+---@field center meth.Vec2
+---@field radius number
+
+---@class meth.Circle
+local methods = {
+	---@param self meth.Circle
+	---@return meth.Vec2, number
+	unpack = function(self)
+		return self[1], self[2]
+	end,
+	---@param self meth.Circle
+	---@return meth.Circle
+	copy = function(self)
+		return circle(self[1], self[2])
+	end,
+	---@param self meth.Circle
+	---@param point meth.Vec2
+	---@return boolean
+	contains = function(self, point)
+		local offset = point - self[1]
+		return offset:dot(offset) <= self[2] * self[2]
+	end,
+	-- `point` itself when it is inside.
+	---@param self meth.Circle
+	---@param point meth.Vec2
+	---@return meth.Vec2
+	closest_point = function(self, point)
+		local offset = point - self[1]
+		local length = offset:length()
+		if length <= self[2] then
+			return point:copy()
+		end
+		return self[1] + offset * (self[2] / length)
+	end,
+	-- 0 inside.
+	---@param self meth.Circle
+	---@param point meth.Vec2
+	---@return number
+	distance_to_point = function(self, point)
+		return math.max((point - self[1]):length() - self[2], 0)
+	end,
+	type = "Circle",
+}
+
+---@type metatable
+local metatable = {
+	__newindex = function(self, key, value)
+		if key == "center" then
+			rawset(self, 1, value)
+		elseif key == "radius" then
+			rawset(self, 2, value)
+		end
+	end,
+	---@param self meth.Circle
+	__index = function(self, value)
+		if value == "center" then
+			return self[1]
+		elseif value == "radius" then
+			return self[2]
+		else
+			return methods[value]
+		end
+	end,
+	__eq = function(a, b)
+		return a[1] == b[1] and a[2] == b[2]
+	end,
+	__len = function()
+		return 2
+	end,
+	__tostring = function(self)
+		return "[" .. tostring(self[1]) .. ", " .. tostring(self[2]) .. "]"
+	end,
+}
+
+---@param center meth.Vec2
+---@param radius number
+---@return meth.Circle
+---@diagnostic disable-next-line: lowercase-global
+function circle(center, radius)
+	return setmetatable({ center:copy(), radius }, metatable)
+end
+
+local inner = _G["__inner"]
+---@type __inner
+local inner = inner ~= nil and inner or {}
+_G["__inner"] = inner
+inner.meth.circle_metatable = metatable
+inner.meth.circle_methods = methods
@@ -8,6 +8,36 @@
 ---@field mat2_metatable metatable
 ---@field mat3_metatable metatable
 ---@field mat4_metatable metatable
+---@field rect_metatable metatable
+---@field irect_metatable metatable
+---@field aabb3_metatable metatable
+---@field ray2_metatable metatable
+---@field ray3_metatable metatable
+---@field plane_metatable metatable
+---@field sphere_metatable metatable
+---@field circle_metatable metatable
+---@field segment2_metatable metatable
+---@field segment3_metatable metatable
+---@field vec2_methods table<string, any>
+---@field ivec2_methods table<string, any>
+---@field vec3_methods table<string, any>
+---@field ivec3_methods table<string, any>
+---@field vec4_methods table<string, any>
+---@field ivec4_methods table<string, any>
+---@field mat2_methods table<string, any>
+---@field mat3_methods table<string, any>
+---@field mat4_methods table<string, any>
+---@field rect_methods table<string, any>
+---@field irect_methods table<string, any>
+---@field aabb3_methods table<string, any>
+---@field ray2_methods table<string, any>
+---@field ray3_methods table<string, any>
+---@field plane_methods table<string, any>
+---@field sphere_methods table<string, any>
+---@field circle_methods table<string, any>
+---@field segment2_methods table<string, any>
+---@field segment3_methods table<string, any>
+---@field searcher function? package searcher of the latest `apply`
 
 ---@meta
 ---@class (exact) __inner
@@ -0,0 +1,56 @@
+-- Integer division and bitwise metamethods of the ivec types. Only loaded on Lua 5.4, LuaJIT can
+-- not parse these operators.
+
+---@param size integer
+---@param metatable metatable
+---@param op fun(a: integer, b: integer): integer
+---@return fun(a: table|integer, b: table|integer): table
+local function binary(size, metatable, op)
+	return function(a, b)
+		local result = {}
+		for i = 1, size do
+			result[i] = op(type(a) == "number" and a or a[i], type(b) == "number" and b or b[i])
+		end
+		return setmetatable(result, metatable)
+	end
+end
+
+---@param size integer
+---@param metatable metatable
+---@return fun(a: table): table
+local function bnot(size, metatable)
+	return function(a)
+		local result = {}
+		for i = 1, size do
+			result[i] = ~a[i]
+		end
+		return setmetatable(result, metatable)
+	end
+end
+
+---@type __inner
+local inner = _G["__inner"]
+for size = 2, 4 do
+	local metatable = inner.meth["ivec" .. size .. "_metatable"]
+	if metatable ~= nil then
+		metatable.__idiv = binary(size, metatable, function(a, b)
+			return a // b
+		end)
+		metatable.__band = binary(size, metatable, function(a, b)
+			return a & b
+		end)
+		metatable.__bor = binary(size, metatable, function(a, b)
+			return a | b
+		end)
+		metatable.__bxor = binary(size, metatable, function(a, b)
+			return a ~ b
+		end)
+		metatable.__shl = binary(size, metatable, function(a, b)
+			return a << b
+		end)
+		metatable.__shr = binary(size, metatable, function(a, b)
+			return a >> b
+		end)
+		metatable.__bnot = bnot(size, metatable)
+	end
+end
@@ -0,0 +1,196 @@
+local meth = require("meth.definitions")
+
+---@class meth.IRect
+---@field min meth.IVec2
+---@field max meth.IVec2
+
+---@param self meth.IRect
+---@return meth.IVec2
+local function size(self)
+	return self[2] - self[1]
+end
+
+---@class meth.IRect
+local methods = {
+	---@param self meth.IRect
+	---@return meth.IVec2, meth.IVec2
+	unpack = function(self)
+		return self[1], self[2]
+	end,
+	---@param self meth.IRect
+	---@param rhs meth.IRect
+	---@return meth.IRect
+	assign_from = function(self, rhs)
+		self[1] = rhs[1]:copy()
+		self[2] = rhs[2]:copy()
+		return self
+	end,
+	---@param self meth.IRect
+	---@return meth.IRect
+	copy = function(self)
+		return irect(self[1]:copy(), self[2]:copy())
+	end,
+	---@param self meth.IRect
+	---@return meth.IVec2
+	center = function(self)
+		return ivec2(meth.trunc((self[1][1] + self[2][1]) / 2), meth.trunc((self[1][2] + self[2][2]) / 2))
+	end,
+	size = size,
+	---@param self meth.IRect
+	---@return number
+	area = function(self)
+		local size = size(self)
+		return size[1] * size[2]
+	end,
+	---@param self meth.IRect
+	---@param point meth.IVec2
+	---@return boolean
+	contains = function(self, point)
+		for i = 1, 2 do
+			if point[i] < self[1][i] or self[2][i] < point[i] then
+				return false
+			end
+		end
+		return true
+	end,
+	---@param self meth.IRect
+	---@param other meth.IRect
+	---@return boolean
+	intersects = function(self, other)
+		for i = 1, 2 do
+			if other[2][i] < self[1][i] or self[2][i] < other[1][i] then
+				return false
+			end
+		end
+		return true
+	end,
+	---@param self meth.IRect
+	---@param other meth.IRect
+	---@return meth.IRect?
+	intersection = function(self, other)
+		if not self:intersects(other) then
+			return nil
+		end
+		return irect(self[1]:maxed(other[1]), self[2]:mined(other[2]))
+	end,
+	---@param self meth.IRect
+	---@param other meth.IRect
+	---@return meth.IRect
+	union = function(self, other)
+		self[1] = self[1]:mined(other[1])
+		self[2] = self[2]:maxed(other[2])
+		return self
+	end,
+	---@param self meth.IRect
+	---@param other meth.IRect
+	---@return meth.IRect
+	unioned = function(self, other)
+		return irect(self[1]:mined(other[1]), self[2]:maxed(other[2]))
+	end,
+	---@param self meth.IRect
+	---@param amount number
+	---@return meth.IRect
+	expand = function(self, amount)
+		self[1] = self[1] - amount
+		self[2] = self[2] + amount
+		return self
+	end,
+	---@param self meth.IRect
+	---@param amount number
+	---@return meth.IRect
+	expanded = function(self, amount)
+		return irect(self[1] - amount, self[2] + amount)
+	end,
+	---@param self meth.IRect
+	---@param offset meth.IVec2
+	---@return meth.IRect
+	translate = function(self, offset)
+		self[1] = self[1] + offset
+		self[2] = self[2] + offset
+		return self
+	end,
+	---@param self meth.IRect
+	---@param offset meth.IVec2
+	---@return meth.IRect
+	translated = function(self, offset)
+		return irect(self[1] + offset, self[2] + offset)
+	end,
+	---@param self meth.IRect
+	---@param point meth.IVec2
+	---@return meth.IVec2
+	clamp_point = function(self, point)
+		return point:maxed(self[1]):mined(self[2])
+	end,
+	type = "IRect",
+}
+
+---@type metatable
+local metatable = {
+	__newindex = function(self, key, value)
+		if key == "min" then
+			rawset(self, 1, value)
+		elseif key == "max" then
+			rawset(self, 2, value)
+		end
+	end,
+	---@param self meth.IRect
+	__index = function(self, value)
+		if value == "min" then
+			return self[1]
+		elseif value == "max" then
+			return self[2]
+		else
+			return methods[value]
+		end
+	end,
+	__eq = function(a, b)
+		return a[1] == b[1] and a[2] == b[2]
+	end,
+	__len = function()
+		return 2
+	end,
+	__tostring = function(self)
+		return "[" .. tostring(self[1]) .. ", " .. tostring(self[2]) .. "]"
+	end,
+}
+
+---@return meth.IRect
+---@overload fun(min: meth.IVec2, max: meth.IVec2): meth.IRect
+---@overload fun(min_x: number, min_y: number, max_x: number, max_y: number): meth.IRect
+---@overload fun(): meth.IRect
+---@diagnostic disable-next-line: lowercase-global
+function irect(min_x, min_y, max_x, max_y)
+	if type(min_x) == "number" then
+		return setmetatable({ ivec2(min_x, min_y), ivec2(max_x, max_y) }, metatable)
+	elseif min_x ~= nil then
+		return setmetatable({ min_x:copy(), min_y:copy() }, metatable)
+	else
+		return setmetatable({ ivec2(), ivec2() }, metatable)
+	end
+end
+
+local inner = _G["__inner"]
+---@type __inner
+local inner = inner ~= nil and inner or {}
+_G["__inner"] = inner
+inner.meth.irect_metatable = metatable
+inner.meth.irect_methods = methods
+
+---@class meth.IRect.definitions
+local IRect = {
+	---@param min meth.IVec2
+	---@param max meth.IVec2
+	---@return meth.IRect
+	from_min_max = function(min, max)
+		return irect(min, max)
+	end,
+	---@param center meth.IVec2
+	---@param size meth.IVec2
+	---@return meth.IRect
+	from_center_size = function(center, size)
+		local min = center - ivec2(meth.trunc(size[1] / 2), meth.trunc(size[2] / 2))
+		return irect(min, min + size)
+	end,
+}
+
+return IRect
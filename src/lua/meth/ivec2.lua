@@ -1,4 +1,4 @@
-local meth = require("src.lua.meth")
+local meth = require("meth.definitions")
 
 ---@class meth.IVec2
 ---@field x integer
@@ -268,3 +268,4 @@ local inner = _G["__inner"]
 local inner = inner ~= nil and inner or {}
 _G["__inner"] = inner
 inner.meth.ivec2_metatable = metatable
+inner.meth.ivec2_methods = methods
@@ -1,4 +1,4 @@
-local meth = require("src.lua.meth")
+local meth = require("meth.definitions")
 ---@class meth.IVec3
 ---@field x float
 ---@field y float
@@ -297,3 +297,4 @@ local inner = _G["__inner"]
 local inner = inner ~= nil and inner or {}
 _G["__inner"] = inner
 inner.meth.ivec3_metatable = metatable
+inner.meth.ivec3_methods = methods
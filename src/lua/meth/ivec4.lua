@@ -1,4 +1,4 @@
-local meth = require("src.lua.meth")
+local meth = require("meth.definitions")
 ---@class meth.IVec4
 ---@field x integer
 ---@field y integer
@@ -330,3 +330,4 @@ local inner = _G["__inner"]
 local inner = inner ~= nil and inner or {}
 _G["__inner"] = inner
 inner.meth.ivec4_metatable = metatable
+inner.meth.ivec4_methods = methods
@@ -182,6 +182,7 @@ local inner = _G["__inner"]
 local inner = inner ~= nil and inner or {}
 _G["__inner"] = inner
 inner.meth.mat2_metatable = metatable
+inner.meth.mat2_methods = methods
 
 ---@class meth.Mat2.definitions
 local Mat2 = {
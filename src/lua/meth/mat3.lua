@@ -283,6 +283,7 @@ local inner = _G["__inner"]
 local inner = inner ~= nil and inner or {}
 _G["__inner"] = inner
 inner.meth.mat2_metatable = metatable
+inner.meth.mat3_methods = methods
 
 ---@class meth.Mat3.definitions
 local Mat3 = {
@@ -296,40 +297,4 @@ local Mat3 = {
 	IDENTITY = mat3(1, 0, 0, 0, 1, 0, 0, 0, 1),
 }
 
-local t = require("src.lua.lopa-test")
-t.test_module("mat3", {
-	multiplication = function()
-		t.case("matrix * vector", function()
-      -- stylua: ignore
-      local m1 = mat3(
-        1,4,7,
-        2,5,8,
-        3,6,9
-      )
-			local vec = vec3(1, 2, 3)
-			t.assert_eq(m1 * vec, vec3(14., 32., 50.))
-		end)
-		t.case("matrix * matrix", function()
-      -- stylua: ignore
-      local m1 = mat3(
-        1,4,7,
-        2,5,8,
-        3,6,9
-      )
-      -- stylua: ignore
-      local m2 = mat3(
-        9,6,3,
-        8,5,2,
-        7,4,1
-      )
-
-      -- stylua: ignore
-			t.assert_eq(m1 * m2, mat3(
-        30, 84, 138,
-        24, 69, 114,
-        18, 54, 90))
-		end)
-	end,
-})
-
 return Mat3
@@ -30,11 +30,250 @@ local clamp = function(num, min, max)
 	return math.max(math.min(num, max), min)
 end
 
+local unpack = table.unpack or unpack
+
+-- Calls `fun` with numbers, or once per component when some of the arguments are vectors and
+-- builds a vector of the results. Numbers next to vectors count for every component.
+---@generic T: number|meth.Vec2|meth.Vec3|meth.Vec4|meth.IVec2|meth.IVec3|meth.IVec4
+---@param fun fun(...: number): number
+---@param ... T|number
+---@return T
+local function componentwise(fun, ...)
+	local count = select("#", ...)
+	local args = { ... }
+	local first
+	for i = 1, count do
+		if type(args[i]) ~= "number" then
+			first = args[i]
+			break
+		end
+	end
+	if first == nil then
+		return fun(...)
+	end
+	local components = {}
+	for component = 1, #first do
+		local values = {}
+		for i = 1, count do
+			local value = args[i]
+			values[i] = type(value) == "number" and value or value[component]
+		end
+		components[component] = fun(unpack(values, 1, count))
+	end
+	-- the native vectors of Luau are immutable
+	if type(first) == "vector" then
+		---@type __inner
+		local inner = _G["__inner"]
+		return (vector and vector.create or inner.meth.new_vector)(unpack(components))
+	end
+	local result = first:copy()
+	for i, value in ipairs(components) do
+		result[i] = value
+	end
+	return result
+end
+
+-- The functions below take numbers, `componentwise` lets them take vectors as well.
+---@param fun function
+---@return function
+local function lifted(fun)
+	return function(...)
+		return componentwise(fun, ...)
+	end
+end
+
+---@param x number
+---@return number
+local function sign(x)
+	if x > 0 then
+		return 1
+	elseif x < 0 then
+		return -1
+	end
+	return 0
+end
+---@param edge number
+---@param x number
+---@return number
+local function step(edge, x)
+	return x < edge and 0 or 1
+end
+---@param a number
+---@param b number
+---@param t number
+---@return number
+local function mix(a, b, t)
+	return a + (b - a) * t
+end
+---@param a number
+---@param b number
+---@param value number
+---@return number
+local function inverse_lerp(a, b, value)
+	return (value - a) / (b - a)
+end
+---@param x number
+---@return number
+local function saturate(x)
+	return clamp(x, 0, 1)
+end
+---@param edge0 number
+---@param edge1 number
+---@param x number
+---@return number
+local function smoothstep(edge0, edge1, x)
+	local t = saturate(inverse_lerp(edge0, edge1, x))
+	return t * t * (3 - 2 * t)
+end
+---@param edge0 number
+---@param edge1 number
+---@param x number
+---@return number
+local function smootherstep(edge0, edge1, x)
+	local t = saturate(inverse_lerp(edge0, edge1, x))
+	return t * t * t * (t * (6 * t - 15) + 10)
+end
+---@param x number
+---@param from_min number
+---@param from_max number
+---@param to_min number
+---@param to_max number
+---@return number
+local function remap(x, from_min, from_max, to_min, to_max)
+	return mix(to_min, to_max, inverse_lerp(from_min, from_max, x))
+end
+---@param x number
+---@param min number
+---@param max number
+---@return number
+local function wrap(x, min, max)
+	local range = max - min
+	return x - range * math.floor((x - min) / range)
+end
+---@param x number
+---@param length number
+---@return number
+local function ping_pong(x, length)
+	return length - math.abs(wrap(x, 0, 2 * length) - length)
+end
+
+local BACK = 1.70158
+local BACK_IN_OUT = BACK * 1.525
+local ELASTIC = 2 * math.pi / 3
+local ELASTIC_IN_OUT = 2 * math.pi / 4.5
+
+---@param t number
+---@return number
+local function bounce_out(t)
+	local n, d = 7.5625, 2.75
+	if t < 1 / d then
+		return n * t * t
+	elseif t < 2 / d then
+		t = t - 1.5 / d
+		return n * t * t + 0.75
+	elseif t < 2.5 / d then
+		t = t - 2.25 / d
+		return n * t * t + 0.9375
+	end
+	t = t - 2.625 / d
+	return n * t * t + 0.984375
+end
+
+-- The easing curves map `0..1` to `0..1`, like on https://easings.net.
+---@type table<string, fun(t: number): number>
+local curves = {
+	quad_in = function(t)
+		return t * t
+	end,
+	quad_out = function(t)
+		return 1 - (1 - t) * (1 - t)
+	end,
+	quad_in_out = function(t)
+		if t < 0.5 then
+			return 2 * t * t
+		end
+		return 1 - (2 - 2 * t) * (2 - 2 * t) / 2
+	end,
+	cubic_in = function(t)
+		return t * t * t
+	end,
+	cubic_out = function(t)
+		return 1 - (1 - t) * (1 - t) * (1 - t)
+	end,
+	cubic_in_out = function(t)
+		if t < 0.5 then
+			return 4 * t * t * t
+		end
+		return 1 - (2 - 2 * t) * (2 - 2 * t) * (2 - 2 * t) / 2
+	end,
+	elastic_in = function(t)
+		if t <= 0 or t >= 1 then
+			return saturate(t)
+		end
+		return -(2 ^ (10 * t - 10)) * math.sin((10 * t - 10.75) * ELASTIC)
+	end,
+	elastic_out = function(t)
+		if t <= 0 or t >= 1 then
+			return saturate(t)
+		end
+		return 2 ^ (-10 * t) * math.sin((10 * t - 0.75) * ELASTIC) + 1
+	end,
+	elastic_in_out = function(t)
+		if t <= 0 or t >= 1 then
+			return saturate(t)
+		elseif t < 0.5 then
+			return -(2 ^ (20 * t - 10)) * math.sin((20 * t - 11.125) * ELASTIC_IN_OUT) / 2
+		end
+		return 2 ^ (-20 * t + 10) * math.sin((20 * t - 11.125) * ELASTIC_IN_OUT) / 2 + 1
+	end,
+	bounce_in = function(t)
+		return 1 - bounce_out(1 - t)
+	end,
+	bounce_out = bounce_out,
+	bounce_in_out = function(t)
+		if t < 0.5 then
+			return (1 - bounce_out(1 - 2 * t)) / 2
+		end
+		return (1 + bounce_out(2 * t - 1)) / 2
+	end,
+	back_in = function(t)
+		return (BACK + 1) * t * t * t - BACK * t * t
+	end,
+	back_out = function(t)
+		t = t - 1
+		return 1 + (BACK + 1) * t * t * t + BACK * t * t
+	end,
+	back_in_out = function(t)
+		if t < 0.5 then
+			t = 2 * t
+			return t * t * ((BACK_IN_OUT + 1) * t - BACK_IN_OUT) / 2
+		end
+		t = 2 * t - 2
+		return (t * t * ((BACK_IN_OUT + 1) * t + BACK_IN_OUT) + 2) / 2
+	end,
+}
+local easing = {}
+for name, curve in pairs(curves) do
+	easing[name] = lifted(curve)
+end
+
 ---@class (exact) meth.definitions
 return {
 	trunc = trunc,
 	round = round,
 	fract = fract,
 	fract_gl = fract_gl,
-  clamp = clamp
+	clamp = clamp,
+	-- the rest takes numbers or vectors, see `src/functions.rs`
+	sign = lifted(sign),
+	step = lifted(step),
+	smoothstep = lifted(smoothstep),
+	smootherstep = lifted(smootherstep),
+	mix = lifted(mix),
+	inverse_lerp = lifted(inverse_lerp),
+	remap = lifted(remap),
+	saturate = lifted(saturate),
+	wrap = lifted(wrap),
+	ping_pong = lifted(ping_pong),
+	easing = easing,
 }
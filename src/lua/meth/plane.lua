@@ -0,0 +1,108 @@
+---@class meth.Plane
+---@field normal meth.Vec3
+---@field distance number
+
+---@param self meth.Plane
+---@param point meth.Vec3
+---@return number
+local function signed_distance(self, point)
+	return self[1]:dot(point) - self[2]
+end
+
+---@class meth.Plane
+local methods = {
+	---@param self meth.Plane
+	---@return meth.Vec3, number
+	unpack = function(self)
+		return self[1], self[2]
+	end,
+	---@param self meth.Plane
+	---@return meth.Plane
+	copy = function(self)
+		return plane(self[1], self[2])
+	end,
+	-- Positive on the side `normal` points to.
+	signed_distance = signed_distance,
+	---@param self meth.Plane
+	---@param point meth.Vec3
+	---@return number
+	distance_to_point = function(self, point)
+		return math.abs(signed_distance(self, point))
+	end,
+	---@param self meth.Plane
+	---@param point meth.Vec3
+	---@return meth.Vec3
+	closest_point = function(self, point)
+		return point - self[1] * signed_distance(self, point)
+	end,
+	type = "Plane",
+}
+
+---@type metatable
+local metatable = {
+	__newindex = function(self, key, value)
+		if key == "normal" then
+			rawset(self, 1, value)
+		elseif key == "distance" then
+			rawset(self, 2, value)
+		end
+	end,
+	---@param self meth.Plane
+	__index = function(self, value)
+		if value == "normal" then
+			return self[1]
+		elseif value == "distance" then
+			return self[2]
+		else
+			return methods[value]
+		end
+	end,
+	__eq = function(a, b)
+		return a[1] == b[1] and a[2] == b[2]
+	end,
+	__len = function()
+		return 2
+	end,
+	__tostring = function(self)
+		return "[" .. tostring(self[1]) .. ", " .. tostring(self[2]) .. "]"
+	end,
+}
+
+---@param normal meth.Vec3
+---@param distance number
+---@return meth.Plane
+---@diagnostic disable-next-line: lowercase-global
+function plane(normal, distance)
+	-- normalizes `normal`, scaling `distance` along
+	local length = normal:length()
+	return setmetatable({ normal / length, distance / length }, metatable)
+end
+
+local inner = _G["__inner"]
+---@type __inner
+local inner = inner ~= nil and inner or {}
+_G["__inner"] = inner
+inner.meth.plane_metatable = metatable
+inner.meth.plane_methods = methods
+
+---@class meth.Plane.definitions
+local Plane = {
+	---@param point meth.Vec3
+	---@param normal meth.Vec3
+	---@return meth.Plane
+	from_point_normal = function(point, normal)
+		local normal = normal:normalized()
+		return setmetatable({ normal, normal:dot(point) }, metatable)
+	end,
+	-- The normal points to the side from which `a`, `b`, `c` are counter-clockwise.
+	---@param a meth.Vec3
+	---@param b meth.Vec3
+	---@param c meth.Vec3
+	---@return meth.Plane
+	from_points = function(a, b, c)
+		local normal = (b - a):cross(c - a):normalized()
+		return setmetatable({ normal, normal:dot(a) }, metatable)
+	end,
+}
+
+return Plane
@@ -0,0 +1,200 @@
+---@class meth.Ray2
+---@field origin meth.Vec2
+---@field direction meth.Vec2
+
+---@class meth.Hit2
+---@field distance number
+---@field point meth.Vec2
+---@field normal meth.Vec2
+
+-- Below this a ray counts as parallel to a plane, a triangle or an axis.
+local EPSILON = 1e-6
+
+---@param self meth.Ray2
+---@param distance number
+---@return meth.Vec2
+local function at(self, distance)
+	return self[1] + self[2] * distance
+end
+
+---@param self meth.Ray2
+---@param point meth.Vec2
+---@return meth.Vec2
+local function closest_point(self, point)
+	return at(self, math.max((point - self[1]):dot(self[2]), 0))
+end
+
+---@param self meth.Ray2
+---@param bounds meth.Rect
+---@return meth.Hit2?
+local function intersect_bounds(self, bounds)
+	local near, far = -math.huge, math.huge
+	local near_axis, near_sign, far_axis, far_sign
+	for i = 1, 2 do
+		local origin, direction = self[1][i], self[2][i]
+		local min, max = bounds[1][i], bounds[2][i]
+		if math.abs(direction) < EPSILON then
+			if origin < min or origin > max then
+				return nil
+			end
+		else
+			local to_min = (min - origin) / direction
+			local to_max = (max - origin) / direction
+			local enter, exit, sign = to_min, to_max, -1
+			if direction < 0 then
+				enter, exit, sign = to_max, to_min, 1
+			end
+			if enter > near then
+				near, near_axis, near_sign = enter, i, sign
+			end
+			if exit < far then
+				far, far_axis, far_sign = exit, i, -sign
+			end
+		end
+	end
+	if near > far or far < 0 then
+		return nil
+	end
+	-- from inside the bounds the ray hits them on the way out
+	local distance, axis, sign = near, near_axis, near_sign
+	if near < 0 then
+		distance, axis, sign = far, far_axis, far_sign
+	end
+	local normal = { 0, 0, 0 }
+	if axis ~= nil then
+		normal[axis] = sign
+	end
+	return { distance = distance, point = at(self, distance), normal = vec2(normal[1], normal[2]) }
+end
+
+---@param self meth.Ray2
+---@param ball meth.Circle
+---@return meth.Hit2?
+local function intersect_ball(self, ball)
+	local offset = self[1] - ball[1]
+	local b = offset:dot(self[2])
+	local c = offset:dot(offset) - ball[2] * ball[2]
+	-- outside and looking away
+	if c > 0 and b > 0 then
+		return nil
+	end
+	local discriminant = b * b - c
+	if discriminant < 0 then
+		return nil
+	end
+	local root = math.sqrt(discriminant)
+	-- from inside the ball the ray hits it on the way out
+	local distance = c > 0 and -b - root or -b + root
+	local point = at(self, distance)
+	return { distance = distance, point = point, normal = (point - ball[1]) / ball[2] }
+end
+
+---@param normal meth.Vec2
+---@param direction meth.Vec2
+---@return meth.Vec2
+local function facing(normal, direction)
+	if normal:dot(direction) > 0 then
+		return -normal
+	end
+	return normal
+end
+
+---@class meth.Ray2
+local methods = {
+	---@param self meth.Ray2
+	---@return meth.Vec2, meth.Vec2
+	unpack = function(self)
+		return self[1], self[2]
+	end,
+	---@param self meth.Ray2
+	---@return meth.Ray2
+	copy = function(self)
+		return ray2(self[1], self[2])
+	end,
+	at = at,
+	closest_point = closest_point,
+	---@param self meth.Ray2
+	---@param point meth.Vec2
+	---@return number
+	distance_to_point = function(self, point)
+		return (point - closest_point(self, point)):length()
+	end,
+	---@param self meth.Ray2
+	---@param circle meth.Circle
+	---@return meth.Hit2?
+	intersect_circle = intersect_ball,
+	---@param self meth.Ray2
+	---@param rect meth.Rect
+	---@return meth.Hit2?
+	intersect_rect = intersect_bounds,
+	---@param self meth.Ray2
+	---@param segment meth.Segment2
+	---@return meth.Hit2?
+	intersect_segment = function(self, segment)
+		local function perp_dot(a, b)
+			return a[1] * b[2] - a[2] * b[1]
+		end
+		local edge = segment[2] - segment[1]
+		local denominator = perp_dot(self[2], edge)
+		if math.abs(denominator) < EPSILON then
+			return nil
+		end
+		local offset = segment[1] - self[1]
+		local distance = perp_dot(offset, edge) / denominator
+		local s = perp_dot(offset, self[2]) / denominator
+		if distance < 0 or s < 0 or s > 1 then
+			return nil
+		end
+		return {
+			distance = distance,
+			point = at(self, distance),
+			normal = facing(vec2(-edge[2], edge[1]):normalized(), self[2]),
+		}
+	end,
+	type = "Ray2",
+}
+
+---@type metatable
+local metatable = {
+	__newindex = function(self, key, value)
+		if key == "origin" then
+			rawset(self, 1, value)
+		elseif key == "direction" then
+			rawset(self, 2, value)
+		end
+	end,
+	---@param self meth.Ray2
+	__index = function(self, value)
+		if value == "origin" then
+			return self[1]
+		elseif value == "direction" then
+			return self[2]
+		else
+			return methods[value]
+		end
+	end,
+	__eq = function(a, b)
+		return a[1] == b[1] and a[2] == b[2]
+	end,
+	__len = function()
+		return 2
+	end,
+	__tostring = function(self)
+		return "[" .. tostring(self[1]) .. ", " .. tostring(self[2]) .. "]"
+	end,
+}
+
+---@param origin meth.Vec2
+---@param direction meth.Vec2
+---@return meth.Ray2
+---@diagnostic disable-next-line: lowercase-global
+function ray2(origin, direction)
+	return setmetatable({ origin:copy(), direction:normalized() }, metatable)
+end
+
+local inner = _G["__inner"]
+---@type __inner
+local inner = inner ~= nil and inner or {}
+_G["__inner"] = inner
+inner.meth.ray2_metatable = metatable
+inner.meth.ray2_methods = methods
@@ -0,0 +1,229 @@
+---@class meth.Ray3
+---@field origin meth.Vec3
+---@field direction meth.Vec3
+
+---@class meth.Hit3
+---@field distance number
+---@field point meth.Vec3
+---@field normal meth.Vec3
+
+-- Below this a ray counts as parallel to a plane, a triangle or an axis.
+local EPSILON = 1e-6
+
+---@param self meth.Ray3
+---@param distance number
+---@return meth.Vec3
+local function at(self, distance)
+	return self[1] + self[2] * distance
+end
+
+---@param self meth.Ray3
+---@param point meth.Vec3
+---@return meth.Vec3
+local function closest_point(self, point)
+	return at(self, math.max((point - self[1]):dot(self[2]), 0))
+end
+
+---@param self meth.Ray3
+---@param bounds meth.Aabb3
+---@return meth.Hit3?
+local function intersect_bounds(self, bounds)
+	local near, far = -math.huge, math.huge
+	local near_axis, near_sign, far_axis, far_sign
+	for i = 1, 3 do
+		local origin, direction = self[1][i], self[2][i]
+		local min, max = bounds[1][i], bounds[2][i]
+		if math.abs(direction) < EPSILON then
+			if origin < min or origin > max then
+				return nil
+			end
+		else
+			local to_min = (min - origin) / direction
+			local to_max = (max - origin) / direction
+			local enter, exit, sign = to_min, to_max, -1
+			if direction < 0 then
+				enter, exit, sign = to_max, to_min, 1
+			end
+			if enter > near then
+				near, near_axis, near_sign = enter, i, sign
+			end
+			if exit < far then
+				far, far_axis, far_sign = exit, i, -sign
+			end
+		end
+	end
+	if near > far or far < 0 then
+		return nil
+	end
+	-- from inside the bounds the ray hits them on the way out
+	local distance, axis, sign = near, near_axis, near_sign
+	if near < 0 then
+		distance, axis, sign = far, far_axis, far_sign
+	end
+	local normal = { 0, 0, 0 }
+	if axis ~= nil then
+		normal[axis] = sign
+	end
+	return { distance = distance, point = at(self, distance), normal = vec3(normal[1], normal[2], normal[3]) }
+end
+
+---@param self meth.Ray3
+---@param ball meth.Sphere
+---@return meth.Hit3?
+local function intersect_ball(self, ball)
+	local offset = self[1] - ball[1]
+	local b = offset:dot(self[2])
+	local c = offset:dot(offset) - ball[2] * ball[2]
+	-- outside and looking away
+	if c > 0 and b > 0 then
+		return nil
+	end
+	local discriminant = b * b - c
+	if discriminant < 0 then
+		return nil
+	end
+	local root = math.sqrt(discriminant)
+	-- from inside the ball the ray hits it on the way out
+	local distance = c > 0 and -b - root or -b + root
+	local point = at(self, distance)
+	return { distance = distance, point = point, normal = (point - ball[1]) / ball[2] }
+end
+
+---@param normal meth.Vec3
+---@param direction meth.Vec3
+---@return meth.Vec3
+local function facing(normal, direction)
+	if normal:dot(direction) > 0 then
+		return -normal
+	end
+	return normal
+end
+
+---@class meth.Ray3
+local methods = {
+	---@param self meth.Ray3
+	---@return meth.Vec3, meth.Vec3
+	unpack = function(self)
+		return self[1], self[2]
+	end,
+	---@param self meth.Ray3
+	---@return meth.Ray3
+	copy = function(self)
+		return ray3(self[1], self[2])
+	end,
+	at = at,
+	closest_point = closest_point,
+	---@param self meth.Ray3
+	---@param point meth.Vec3
+	---@return number
+	distance_to_point = function(self, point)
+		return (point - closest_point(self, point)):length()
+	end,
+	---@param self meth.Ray3
+	---@param sphere meth.Sphere
+	---@return meth.Hit3?
+	intersect_sphere = intersect_ball,
+	---@param self meth.Ray3
+	---@param aabb meth.Aabb3
+	---@return meth.Hit3?
+	intersect_aabb = intersect_bounds,
+	---@param self meth.Ray3
+	---@param plane meth.Plane
+	---@return meth.Hit3?
+	intersect_plane = function(self, plane)
+		local denominator = plane[1]:dot(self[2])
+		if math.abs(denominator) < EPSILON then
+			return nil
+		end
+		local distance = (plane[2] - plane[1]:dot(self[1])) / denominator
+		if distance < 0 then
+			return nil
+		end
+		return {
+			distance = distance,
+			point = at(self, distance),
+			normal = denominator < 0 and plane[1]:copy() or -plane[1],
+		}
+	end,
+	-- Möller–Trumbore, both sides of the triangle count.
+	---@param self meth.Ray3
+	---@param a meth.Vec3
+	---@param b meth.Vec3
+	---@param c meth.Vec3
+	---@return meth.Hit3?
+	intersect_triangle = function(self, a, b, c)
+		local edge1 = b - a
+		local edge2 = c - a
+		local p = self[2]:cross(edge2)
+		local determinant = edge1:dot(p)
+		if math.abs(determinant) < EPSILON then
+			return nil
+		end
+		local inverse = 1 / determinant
+		local offset = self[1] - a
+		local u = offset:dot(p) * inverse
+		if u < 0 or u > 1 then
+			return nil
+		end
+		local q = offset:cross(edge1)
+		local v = self[2]:dot(q) * inverse
+		if v < 0 or u + v > 1 then
+			return nil
+		end
+		local distance = edge2:dot(q) * inverse
+		if distance < 0 then
+			return nil
+		end
+		return {
+			distance = distance,
+			point = at(self, distance),
+			normal = facing(edge1:cross(edge2):normalized(), self[2]),
+		}
+	end,
+	type = "Ray3",
+}
+
+---@type metatable
+local metatable = {
+	__newindex = function(self, key, value)
+		if key == "origin" then
+			rawset(self, 1, value)
+		elseif key == "direction" then
+			rawset(self, 2, value)
+		end
+	end,
+	---@param self meth.Ray3
+	__index = function(self, value)
+		if value == "origin" then
+			return self[1]
+		elseif value == "direction" then
+			return self[2]
+		else
+			return methods[value]
+		end
+	end,
+	__eq = function(a, b)
+		return a[1] == b[1] and a[2] == b[2]
+	end,
+	__len = function()
+		return 2
+	end,
+	__tostring = function(self)
+		return "[" .. tostring(self[1]) .. ", " .. tostring(self[2]) .. "]"
+	end,
+}
+
+---@param origin meth.Vec3
+---@param direction meth.Vec3
+---@return meth.Ray3
+---@diagnostic disable-next-line: lowercase-global
+function ray3(origin, direction)
+	return setmetatable({ origin:copy(), direction:normalized() }, metatable)
+end
+
+local inner = _G["__inner"]
+---@type __inner
+local inner = inner ~= nil and inner or {}
+_G["__inner"] = inner
+inner.meth.ray3_metatable = metatable
+inner.meth.ray3_methods = methods
@@ -0,0 +1,194 @@
+---@class meth.Rect
+---@field min meth.Vec2
+---@field max meth.Vec2
+
+---@param self meth.Rect
+---@return meth.Vec2
+local function size(self)
+	return self[2] - self[1]
+end
+
+---@class meth.Rect
+local methods = {
+	---@param self meth.Rect
+	---@return meth.Vec2, meth.Vec2
+	unpack = function(self)
+		return self[1], self[2]
+	end,
+	---@param self meth.Rect
+	---@param rhs meth.Rect
+	---@return meth.Rect
+	assign_from = function(self, rhs)
+		self[1] = rhs[1]:copy()
+		self[2] = rhs[2]:copy()
+		return self
+	end,
+	---@param self meth.Rect
+	---@return meth.Rect
+	copy = function(self)
+		return rect(self[1]:copy(), self[2]:copy())
+	end,
+	---@param self meth.Rect
+	---@return meth.Vec2
+	center = function(self)
+		return (self[1] + self[2]) * 0.5
+	end,
+	size = size,
+	---@param self meth.Rect
+	---@return number
+	area = function(self)
+		local size = size(self)
+		return size[1] * size[2]
+	end,
+	---@param self meth.Rect
+	---@param point meth.Vec2
+	---@return boolean
+	contains = function(self, point)
+		for i = 1, 2 do
+			if point[i] < self[1][i] or self[2][i] < point[i] then
+				return false
+			end
+		end
+		return true
+	end,
+	---@param self meth.Rect
+	---@param other meth.Rect
+	---@return boolean
+	intersects = function(self, other)
+		for i = 1, 2 do
+			if other[2][i] < self[1][i] or self[2][i] < other[1][i] then
+				return false
+			end
+		end
+		return true
+	end,
+	---@param self meth.Rect
+	---@param other meth.Rect
+	---@return meth.Rect?
+	intersection = function(self, other)
+		if not self:intersects(other) then
+			return nil
+		end
+		return rect(self[1]:maxed(other[1]), self[2]:mined(other[2]))
+	end,
+	---@param self meth.Rect
+	---@param other meth.Rect
+	---@return meth.Rect
+	union = function(self, other)
+		self[1] = self[1]:mined(other[1])
+		self[2] = self[2]:maxed(other[2])
+		return self
+	end,
+	---@param self meth.Rect
+	---@param other meth.Rect
+	---@return meth.Rect
+	unioned = function(self, other)
+		return rect(self[1]:mined(other[1]), self[2]:maxed(other[2]))
+	end,
+	---@param self meth.Rect
+	---@param amount number
+	---@return meth.Rect
+	expand = function(self, amount)
+		self[1] = self[1] - amount
+		self[2] = self[2] + amount
+		return self
+	end,
+	---@param self meth.Rect
+	---@param amount number
+	---@return meth.Rect
+	expanded = function(self, amount)
+		return rect(self[1] - amount, self[2] + amount)
+	end,
+	---@param self meth.Rect
+	---@param offset meth.Vec2
+	---@return meth.Rect
+	translate = function(self, offset)
+		self[1] = self[1] + offset
+		self[2] = self[2] + offset
+		return self
+	end,
+	---@param self meth.Rect
+	---@param offset meth.Vec2
+	---@return meth.Rect
+	translated = function(self, offset)
+		return rect(self[1] + offset, self[2] + offset)
+	end,
+	---@param self meth.Rect
+	---@param point meth.Vec2
+	---@return meth.Vec2
+	clamp_point = function(self, point)
+		return point:maxed(self[1]):mined(self[2])
+	end,
+	type = "Rect",
+}
+
+---@type metatable
+local metatable = {
+	__newindex = function(self, key, value)
+		if key == "min" then
+			rawset(self, 1, value)
+		elseif key == "max" then
+			rawset(self, 2, value)
+		end
+	end,
+	---@param self meth.Rect
+	__index = function(self, value)
+		if value == "min" then
+			return self[1]
+		elseif value == "max" then
+			return self[2]
+		else
+			return methods[value]
+		end
+	end,
+	__eq = function(a, b)
+		return a[1] == b[1] and a[2] == b[2]
+	end,
+	__len = function()
+		return 2
+	end,
+	__tostring = function(self)
+		return "[" .. tostring(self[1]) .. ", " .. tostring(self[2]) .. "]"
+	end,
+}
+
+---@return meth.Rect
+---@overload fun(min: meth.Vec2, max: meth.Vec2): meth.Rect
+---@overload fun(min_x: number, min_y: number, max_x: number, max_y: number): meth.Rect
+---@overload fun(): meth.Rect
+---@diagnostic disable-next-line: lowercase-global
+function rect(min_x, min_y, max_x, max_y)
+	if type(min_x) == "number" then
+		return setmetatable({ vec2(min_x, min_y), vec2(max_x, max_y) }, metatable)
+	elseif min_x ~= nil then
+		return setmetatable({ min_x:copy(), min_y:copy() }, metatable)
+	else
+		return setmetatable({ vec2(), vec2() }, metatable)
+	end
+end
+
+local inner = _G["__inner"]
+---@type __inner
+local inner = inner ~= nil and inner or {}
+_G["__inner"] = inner
+inner.meth.rect_metatable = metatable
+inner.meth.rect_methods = methods
+
+---@class meth.Rect.definitions
+local Rect = {
+	---@param min meth.Vec2
+	---@param max meth.Vec2
+	---@return meth.Rect
+	from_min_max = function(min, max)
+		return rect(min, max)
+	end,
+	---@param center meth.Vec2
+	---@param size meth.Vec2
+	---@return meth.Rect
+	from_center_size = function(center, size)
+		local min = center - size * 0.5
+		return rect(min, min + size)
+	end,
+}
+
+return Rect
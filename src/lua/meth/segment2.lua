@@ -0,0 +1,88 @@
+---@class meth.Segment2
+---@field a meth.Vec2
+---@field b meth.Vec2
+
+---@param self meth.Segment2
+---@param point meth.Vec2
+---@return meth.Vec2
+local function closest_point(self, point)
+	local edge = self[2] - self[1]
+	local length_squared = edge:dot(edge)
+	if length_squared == 0 then
+		return self[1]:copy()
+	end
+	local s = math.max(math.min((point - self[1]):dot(edge) / length_squared, 1), 0)
+	return self[1] + edge * s
+end
+
+---@class meth.Segment2
+local methods = {
+	---@param self meth.Segment2
+	---@return meth.Vec2, meth.Vec2
+	unpack = function(self)
+		return self[1], self[2]
+	end,
+	---@param self meth.Segment2
+	---@return meth.Segment2
+	copy = function(self)
+		return segment2(self[1], self[2])
+	end,
+	---@param self meth.Segment2
+	---@return number
+	length = function(self)
+		return (self[2] - self[1]):length()
+	end,
+	closest_point = closest_point,
+	---@param self meth.Segment2
+	---@param point meth.Vec2
+	---@return number
+	distance_to_point = function(self, point)
+		return (point - closest_point(self, point)):length()
+	end,
+	type = "Segment2",
+}
+
+---@type metatable
+local metatable = {
+	__newindex = function(self, key, value)
+		if key == "a" then
+			rawset(self, 1, value)
+		elseif key == "b" then
+			rawset(self, 2, value)
+		end
+	end,
+	---@param self meth.Segment2
+	__index = function(self, value)
+		if value == "a" then
+			return self[1]
+		elseif value == "b" then
+			return self[2]
+		else
+			return methods[value]
+		end
+	end,
+	__eq = function(a, b)
+		return a[1] == b[1] and a[2] == b[2]
+	end,
+	__len = function()
+		return 2
+	end,
+	__tostring = function(self)
+		return "[" .. tostring(self[1]) .. ", " .. tostring(self[2]) .. "]"
+	end,
+}
+
+---@param a meth.Vec2
+---@param b meth.Vec2
+---@return meth.Segment2
+---@diagnostic disable-next-line: lowercase-global
+function segment2(a, b)
+	return setmetatable({ a:copy(), b:copy() }, metatable)
+end
+
+local inner = _G["__inner"]
+---@type __inner
+local inner = inner ~= nil and inner or {}
+_G["__inner"] = inner
+inner.meth.segment2_metatable = metatable
+inner.meth.segment2_methods = methods
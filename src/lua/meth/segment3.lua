@@ -0,0 +1,88 @@
+---@class meth.Segment3
+---@field a meth.Vec3
+---@field b meth.Vec3
+
+---@param self meth.Segment3
+---@param point meth.Vec3
+---@return meth.Vec3
+local function closest_point(self, point)
+	local edge = self[2] - self[1]
+	local length_squared = edge:dot(edge)
+	if length_squared == 0 then
+		return self[1]:copy()
+	end
+	local s = math.max(math.min((point - self[1]):dot(edge) / length_squared, 1), 0)
+	return self[1] + edge * s
+end
+
+---@class meth.Segment3
+local methods = {
+	---@param self meth.Segment3
+	---@return meth.Vec3, meth.Vec3
+	unpack = function(self)
+		return self[1], self[2]
+	end,
+	---@param self meth.Segment3
+	---@return meth.Segment3
+	copy = function(self)
+		return segment3(self[1], self[2])
+	end,
+	---@param self meth.Segment3
+	---@return number
+	length = function(self)
+		return (self[2] - self[1]):length()
+	end,
+	closest_point = closest_point,
+	---@param self meth.Segment3
+	---@param point meth.Vec3
+	---@return number
+	distance_to_point = function(self, point)
+		return (point - closest_point(self, point)):length()
+	end,
+	type = "Segment3",
+}
+
+---@type metatable
+local metatable = {
+	__newindex = function(self, key, value)
+		if key == "a" then
+			rawset(self, 1, value)
+		elseif key == "b" then
+			rawset(self, 2, value)
+		end
+	end,
+	---@param self meth.Segment3
+	__index = function(self, value)
+		if value == "a" then
+			return self[1]
+		elseif value == "b" then
+			return self[2]
+		else
+			return methods[value]
+		end
+	end,
+	__eq = function(a, b)
+		return a[1] == b[1] and a[2] == b[2]
+	end,
+	__len = function()
+		return 2
+	end,
+	__tostring = function(self)
+		return "[" .. tostring(self[1]) .. ", " .. tostring(self[2]) .. "]"
+	end,
+}
+
+---@param a meth.Vec3
+---@param b meth.Vec3
+---@return meth.Segment3
+---@diagnostic disable-next-line: lowercase-global
+function segment3(a, b)
+	return setmetatable({ a:copy(), b:copy() }, metatable)
+end
+
+local inner = _G["__inner"]
+---@type __inner
+local inner = inner ~= nil and inner or {}
+_G["__inner"] = inner
+inner.meth.segment3_metatable = metatable
+inner.meth.segment3_methods = methods
@@ -0,0 +1,89 @@
+---@class meth.Sphere
+---@field center meth.Vec3
+---@field radius number
+
+---@class meth.Sphere
+local methods = {
+	---@param self meth.Sphere
+	---@return meth.Vec3, number
+	unpack = function(self)
+		return self[1], self[2]
+	end,
+	---@param self meth.Sphere
+	---@return meth.Sphere
+	copy = function(self)
+		return sphere(self[1], self[2])
+	end,
+	---@param self meth.Sphere
+	---@param point meth.Vec3
+	---@return boolean
+	contains = function(self, point)
+		local offset = point - self[1]
+		return offset:dot(offset) <= self[2] * self[2]
+	end,
+	-- `point` itself when it is inside.
+	---@param self meth.Sphere
+	---@param point meth.Vec3
+	---@return meth.Vec3
+	closest_point = function(self, point)
+		local offset = point - self[1]
+		local length = offset:length()
+		if length <= self[2] then
+			return point:copy()
+		end
+		return self[1] + offset * (self[2] / length)
+	end,
+	-- 0 inside.
+	---@param self meth.Sphere
+	---@param point meth.Vec3
+	---@return number
+	distance_to_point = function(self, point)
+		return math.max((point - self[1]):length() - self[2], 0)
+	end,
+	type = "Sphere",
+}
+
+---@type metatable
+local metatable = {
+	__newindex = function(self, key, value)
+		if key == "center" then
+			rawset(self, 1, value)
+		elseif key == "radius" then
+			rawset(self, 2, value)
+		end
+	end,
+	---@param self meth.Sphere
+	__index = function(self, value)
+		if value == "center" then
+			return self[1]
+		elseif value == "radius" then
+			return self[2]
+		else
+			return methods[value]
+		end
+	end,
+	__eq = function(a, b)
+		return a[1] == b[1] and a[2] == b[2]
+	end,
+	__len = function()
+		return 2
+	end,
+	__tostring = function(self)
+		return "[" .. tostring(self[1]) .. ", " .. tostring(self[2]) .. "]"
+	end,
+}
+
+---@param center meth.Vec3
+---@param radius number
+---@return meth.Sphere
+---@diagnostic disable-next-line: lowercase-global
+function sphere(center, radius)
+	return setmetatable({ center:copy(), radius }, metatable)
+end
+
+local inner = _G["__inner"]
+---@type __inner
+local inner = inner ~= nil and inner or {}
+_G["__inner"] = inner
+inner.meth.sphere_metatable = metatable
+inner.meth.sphere_methods = methods
@@ -1,4 +1,4 @@
-local meth = require("src.lua.meth")
+local meth = require("meth.definitions")
 
 ---@class meth.Vec2
 ---@field x float
@@ -439,32 +439,4 @@ local inner = _G["__inner"]
 local inner = inner ~= nil and inner or {}
 _G["__inner"] = inner
 inner.meth.vec2_metatable = metatable
-
-local test = require("src.lua.lopa-test")
-test.test_module("vec2", {
-	gc = function()
-		do
-			---@type metatable
-			local metatable = {
-				__gc = function()
-					print("collected!")
-				end,
-			}
-			local x = {}
-			setmetatable(x, metatable)
-		end
-    collectgarbage("collect")
-    collectgarbage("collect")
-	end,
-	addition = function()
-		test.case("vec + vec", function()
-			test.assert_eq(vec2(1, 1) + vec2(2, 3), vec2(3, 4))
-		end)
-		test.case("vec + num", function()
-			test.assert_eq(vec2(1, 2) + 2, vec2(3, 4))
-		end)
-		test.case("num + vec", function()
-			test.assert_eq(2 + vec2(1, 2), vec2(3, 4))
-		end)
-	end,
-})
+inner.meth.vec2_methods = methods
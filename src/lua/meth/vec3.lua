@@ -1,4 +1,4 @@
-local meth = require("src.lua.meth")
+local meth = require("meth.definitions")
 ---@class meth.Vec3
 ---@field x float
 ---@field y float
@@ -262,6 +262,16 @@ local methods = {
 		return self[1] * rhs[1] + self[2] * rhs[2] + self[3] * rhs[3]
 	end,
 	---@param self meth.Vec3
+	---@param rhs meth.Vec3
+	---@return meth.Vec3
+	cross = function(self, rhs)
+		return vec3(
+			self[2] * rhs[3] - self[3] * rhs[2],
+			self[3] * rhs[1] - self[1] * rhs[3],
+			self[1] * rhs[2] - self[2] * rhs[1]
+		)
+	end,
+	---@param self meth.Vec3
 	length = function(self)
 		return math.sqrt(self.x * self.x + self.y * self.y + self.z * self.z)
 	end,
@@ -474,3 +484,4 @@ local inner = _G["__inner"]
 local inner = inner ~= nil and inner or {}
 _G["__inner"] = inner
 inner.meth.vec3_metatable = metatable
+inner.meth.vec3_methods = methods
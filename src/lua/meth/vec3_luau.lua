@@ -0,0 +1,304 @@
+local meth = require("meth.definitions")
+-- Vec3 on Luau is the native `vector` value type. Vectors are immutable, so the in-place methods
+-- (`lerp`, `normalize`, ...) raise an error pointing to the variant returning the new value.
+
+---@type __inner
+local inner = _G["__inner"]
+local create = vector and vector.create or inner.meth.new_vector
+
+---@param x number
+---@param y number
+---@param z number
+---@return meth.Vec3
+local function new(x, y, z)
+	return create(x, y, z)
+end
+
+---@param self meth.Vec3
+---@param fun fun(value: number): number
+---@return meth.Vec3
+local function map(self, fun)
+	return new(fun(self.x), fun(self.y), fun(self.z))
+end
+
+---@param a number|meth.Vec3
+---@param b number|meth.Vec3
+---@param fun fun(a: number, b: number): number
+---@return meth.Vec3
+local function zip(a, b, fun)
+	if type(a) == "number" then
+		return new(fun(a, b.x), fun(a, b.y), fun(a, b.z))
+	elseif type(b) == "number" then
+		return new(fun(a.x, b), fun(a.y, b), fun(a.z, b))
+	else
+		return new(fun(a.x, b.x), fun(a.y, b.y), fun(a.z, b.z))
+	end
+end
+
+---The in-place methods of the other backends, calling one fails instead of leaving `self` unchanged.
+---@param name string
+---@param replacement string
+---@return fun(self: meth.Vec3, ...): meth.Vec3
+local function in_place(name, replacement)
+	return function()
+		error(
+			string.format("`Vec3:%s` cannot change an immutable Luau vector, use `Vec3:%s` instead", name, replacement),
+			2
+		)
+	end
+end
+
+local function mod(a, b)
+	return a % b
+end
+
+local function pow(a, b)
+	return a ^ b
+end
+
+---@param self meth.Vec3
+---@return number
+local function length(self)
+	return math.sqrt(self.x * self.x + self.y * self.y + self.z * self.z)
+end
+
+---@param self meth.Vec3
+---@param rhs meth.Vec3
+---@param s float
+---@return meth.Vec3
+local function lerped(self, rhs, s)
+	return self * (1.0 - s) + rhs * s
+end
+
+---@param self meth.Vec3
+---@return meth.Vec3
+local function normalized(self)
+	return self / length(self)
+end
+
+---@param self meth.Vec3
+---@param min meth.Vec3
+---@param max meth.Vec3
+---@return meth.Vec3
+local function clamped(self, min, max)
+	return new(
+		meth.clamp(self.x, min.x, max.x),
+		meth.clamp(self.y, min.y, max.y),
+		meth.clamp(self.z, min.z, max.z)
+	)
+end
+
+---@param self meth.Vec3
+---@param rhs meth.Vec3
+---@return meth.Vec3
+local function mined(self, rhs)
+	return zip(self, rhs, math.min)
+end
+
+---@param self meth.Vec3
+---@param rhs meth.Vec3
+---@return meth.Vec3
+local function maxed(self, rhs)
+	return zip(self, rhs, math.max)
+end
+
+---@param self meth.Vec3
+---@param rhs meth.Vec3
+---@return meth.Vec3
+local function midpointed(self, rhs)
+	return (self + rhs) * 0.5
+end
+
+---@param self meth.Vec3
+---@param rhs meth.Vec3
+---@param d float
+---@return meth.Vec3
+local function move_towardsed(self, rhs, d)
+	local a = rhs - self
+	local len = length(a)
+	if len <= d or len <= 0.0001 then
+		return rhs
+	end
+	return self + a / len * d
+end
+
+---@param self meth.Vec3
+---@return meth.Vec3
+local function fract_gled(self)
+	return map(self, meth.fract_gl)
+end
+
+---@param self meth.Vec3
+---@return meth.Vec3
+local function trunced(self)
+	return map(self, meth.trunc)
+end
+
+---@param self meth.Vec3
+---@return meth.Vec3
+local function fracted(self)
+	return map(self, meth.fract)
+end
+
+---@param self meth.Vec3
+---@return meth.Vec3
+local function rounded(self)
+	return map(self, meth.round)
+end
+
+---@param self meth.Vec3
+---@return meth.Vec3
+local function ceiled(self)
+	return map(self, math.ceil)
+end
+
+---@param self meth.Vec3
+---@return meth.Vec3
+local function floored(self)
+	return map(self, math.floor)
+end
+
+---@class meth.Vec3
+local methods = {
+	---@param self meth.Vec3
+	---@return meth.IVec3
+	as_ivec3 = function(self)
+		return ivec3(math.floor(self.x), math.floor(self.y), math.floor(self.z))
+	end,
+	---@param self meth.Vec3
+	---@return number, number, number
+	unpack = function(self)
+		return self.x, self.y, self.z
+	end,
+	---@param self meth.Vec3
+	---@return meth.Vec3
+	copy = function(self)
+		return self
+	end,
+	lerped = lerped,
+	lerp = in_place("lerp", "lerped"),
+	fract_gled = fract_gled,
+	fract_gl = in_place("fract_gl", "fract_gled"),
+	trunced = trunced,
+	trunc = in_place("trunc", "trunced"),
+	fracted = fracted,
+	fract = in_place("fract", "fracted"),
+	rounded = rounded,
+	round = in_place("round", "rounded"),
+	ceiled = ceiled,
+	ceil = in_place("ceil", "ceiled"),
+	floored = floored,
+	floor = in_place("floor", "floored"),
+	normalized = normalized,
+	normalize = in_place("normalize", "normalized"),
+	clamped = clamped,
+	clamp = in_place("clamp", "clamped"),
+	mined = mined,
+	min = in_place("min", "mined"),
+	maxed = maxed,
+	max = in_place("max", "maxed"),
+	midpointed = midpointed,
+	midpoint = in_place("midpoint", "midpointed"),
+	move_towardsed = move_towardsed,
+	move_towards = in_place("move_towards", "move_towardsed"),
+	---@param self meth.Vec3
+	---@param rhs meth.Vec3
+	---@return number
+	dot = function(self, rhs)
+		return self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+	end,
+	---@param self meth.Vec3
+	---@param rhs meth.Vec3
+	---@return meth.Vec3
+	cross = function(self, rhs)
+		return new(self.y * rhs.z - self.z * rhs.y, self.z * rhs.x - self.x * rhs.z, self.x * rhs.y - self.y * rhs.x)
+	end,
+	length = length,
+	---@param self meth.Vec3
+	---@param value number|meth.Vec3
+	---@return meth.Vec3
+	add = function(self, value)
+		return self + value
+	end,
+	---@param self meth.Vec3
+	---@param value number|meth.Vec3
+	---@return meth.Vec3
+	sub = function(self, value)
+		return self - value
+	end,
+	---@param self meth.Vec3
+	---@param value number|meth.Vec3
+	---@return meth.Vec3
+	div = function(self, value)
+		return self / value
+	end,
+	---@param self meth.Vec3
+	---@param value number|meth.Vec3
+	---@return meth.Vec3
+	mul = function(self, value)
+		return self * value
+	end,
+	---@param self meth.Vec3
+	---@param value number|meth.Vec3
+	---@return meth.Vec3
+	mod = function(self, value)
+		return zip(self, value, mod)
+	end,
+	---@param self meth.Vec3
+	---@param value number|meth.Vec3
+	---@return meth.Vec3
+	pow = function(self, value)
+		return zip(self, value, pow)
+	end,
+	---@param self meth.Vec3
+	---@return meth.Vec3
+	neg = function(self)
+		return -self
+	end,
+	type = "Vec3",
+}
+
+local COMPONENTS = { "x", "y", "z" }
+
+-- Rust installs this as the metatable of every vector. `x`, `y`, `z` and the arithmetic
+-- operators are native, numeric indices keep code written for the table types working.
+---@type metatable
+local metatable = {
+	__index = function(self, key)
+		local component = COMPONENTS[key]
+		if component ~= nil then
+			return self[component]
+		end
+		return methods[key]
+	end,
+	__mod = function(a, b)
+		return zip(a, b, mod)
+	end,
+	__pow = function(a, b)
+		return zip(a, b, pow)
+	end,
+	__len = function()
+		return 3
+	end,
+	__tostring = function(self)
+		return "(" .. self.x .. ", " .. self.y .. ", " .. self.z .. ")"
+	end,
+}
+
+---@return meth.Vec3
+---@overload fun(x: number, y: number, z: number): meth.Vec3
+---@overload fun(value: number): meth.Vec3
+---@overload fun(): meth.Vec3
+---@diagnostic disable-next-line: lowercase-global
+function vec3(x, y, z)
+	if not x then
+		return new(0, 0, 0)
+	elseif x and not y and not z then
+		return new(x, x, x)
+	else
+		return new(x, y, z)
+	end
+end
+
+inner.meth.vec3_metatable = metatable
+inner.meth.vec3_methods = methods
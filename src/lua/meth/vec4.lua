@@ -1,4 +1,4 @@
-local meth = require("src.lua.meth")
+local meth = require("meth.definitions")
 ---@class meth.Vec4
 ---@field x float
 ---@field y float
@@ -527,3 +527,4 @@ local inner = _G["__inner"]
 local inner = inner ~= nil and inner or {}
 _G["__inner"] = inner
 inner.meth.vec4_metatable = metatable
+inner.meth.vec4_methods = methods
@@ -0,0 +1,51 @@
+local t = require("lopa-test")
+
+---@param fun fun()
+---@return string message of the error `fun` raised
+local function failure(fun)
+	local success, err = pcall(fun)
+	t.assert(not success)
+	return tostring(err)
+end
+
+---@param message string
+---@param text string
+local function assert_contains(message, text)
+	t.assert(message:find(text, 1, true) ~= nil)
+end
+
+t.test_module("assertions", {
+	vector_failure = function()
+		local message = failure(function()
+			t.assert_eq(vec3(1, 2.5, 3), vec3(1, 5.5, 3))
+		end)
+		assert_contains(message, "assert_eq failed")
+		assert_contains(message, "  actual: " .. tostring(vec3(1, 2.5, 3)))
+		assert_contains(message, "expected: " .. tostring(vec3(1, 5.5, 3)))
+		assert_contains(message, "differs at:\n  y: 2.5 ~= 5.5")
+	end,
+	matrix_failure = function()
+		local message = failure(function()
+			t.assert_eq(mat2(1.5, 2, 3, 4.5), mat2(1.5, 2, 3, 5.5))
+		end)
+		assert_contains(message, "assert_eq failed")
+		assert_contains(message, "  actual: " .. tostring(mat2(1.5, 2, 3, 4.5)):gsub("\n", "\n          "))
+		assert_contains(message, "differs at:\n  y2: 4.5 ~= 5.5")
+	end,
+	nested_table_failure = function()
+		local message = failure(function()
+			t.assert_eq_deep({ a = { b = { 1, 2 } } }, { a = { b = { 1, 3 } } })
+		end)
+		assert_contains(message, "assert_eq_deep failed")
+		assert_contains(message, "  actual: {")
+		assert_contains(message, "expected: {")
+		assert_contains(message, "differs at:\n  a.b[2]: 2 ~= 3")
+	end,
+	neq_deep = function()
+		t.assert_neq_deep({ 1, { 2 } }, { 1, { 3 } })
+		local message = failure(function()
+			t.assert_neq_deep({ 1, { 2 } }, { 1, { 2 } })
+		end)
+		assert_contains(message, "assert_neq_deep failed, both values are equal")
+	end,
+})
@@ -0,0 +1,58 @@
+local t = require("lopa-test")
+
+---@type __inner
+local inner = _G["__inner"]
+local conversions = inner.meth_conversions
+
+t.test_module("bounds", {
+	construction = function()
+		local meth = require("meth")
+		t.assert_eq(rect(0, 1, 2, 3), rect(vec2(0, 1), vec2(2, 3)))
+		t.assert_eq(meth.Rect.from_center_size(vec2(1, 1), vec2(2, 4)), rect(0, -1, 2, 3))
+		t.assert_eq(meth.IRect.from_center_size(ivec2(0, 0), ivec2(3, 4)), irect(-1, -2, 2, 2))
+		t.assert_eq(meth.Aabb3.from_min_max(vec3(0), vec3(1)), aabb3(0, 0, 0, 1, 1, 1))
+		local r = rect(0, 1, 2, 3)
+		t.assert_eq(r.min, vec2(0, 1))
+		r.max = vec2(4, 5)
+		t.assert_eq(r:size(), vec2(4, 4))
+		t.assert_eq(r:center(), vec2(2, 3))
+	end,
+	measures = function()
+		t.assert_eq(rect(0, 0, 2, 4):area(), 8)
+		t.assert_eq(irect(-1, -2, 2, 2):area(), 12)
+		t.assert_eq(aabb3(0, 0, 0, 1, 2, 3):area(), 22)
+		t.assert_eq(aabb3(0, 0, 0, 1, 2, 3):volume(), 6)
+	end,
+	queries = function()
+		local a = rect(0, 0, 2, 2)
+		t.assert(a:contains(vec2(0, 2)))
+		t.assert(not a:contains(vec2(3, 1)))
+		t.assert(a:intersects(rect(1, -1, 3, 1)))
+		t.assert_eq(a:intersection(rect(1, -1, 3, 1)), rect(1, 0, 2, 1))
+		t.assert_eq(a:intersection(rect(5, 5, 6, 6)), nil)
+		t.assert_eq(aabb3(-1, -1, -1, 2, 2, 2):clamp_point(vec3(3, 0.5, -4)), vec3(2, 0.5, -1))
+	end,
+	in_place_and_copying = function()
+		local a = irect(0, 0, 4, 4)
+		local b = a:expanded(-1)
+		t.assert_eq(a, irect(0, 0, 4, 4))
+		t.assert_eq(b, irect(1, 1, 3, 3))
+		t.assert_eq(a:expand(1), irect(-1, -1, 5, 5))
+		t.assert_eq(a, irect(-1, -1, 5, 5))
+
+		local c = rect(0, 0, 1, 1)
+		t.assert_eq(c:unioned(rect(5, 5, 6, 6)), rect(0, 0, 6, 6))
+		t.assert_eq(c, rect(0, 0, 1, 1))
+		c:union(rect(-1, 0, 0, 0)):translate(vec2(1, 1))
+		t.assert_eq(c, rect(0, 1, 2, 2))
+		t.assert_eq(c:copy():translated(vec2(-1, -1)), rect(-1, 0, 1, 1))
+	end,
+	conversions = function()
+		t.assert_eq(conversions.rect(rect(0, 1, 2, 3)), rect(0, 1, 2, 3))
+		t.assert_eq(conversions.irect(irect(0, 1, 2, 3)), irect(0, 1, 2, 3))
+		t.assert_eq(conversions.aabb3(aabb3(0, 1, 2, 3, 4, 5)), aabb3(0, 1, 2, 3, 4, 5))
+		t.assert_error(function()
+			conversions.rect(vec2(1, 2))
+		end, "could not get `Rect`s field `min`")
+	end,
+})
@@ -0,0 +1,35 @@
+local t = require("lopa-test")
+
+---@type __inner
+local inner = _G["__inner"]
+local conversions = inner.meth_conversions
+
+t.test_module("conversions", {
+	roundtrip = function()
+		t.assert_eq(conversions.vec2(vec2(1, 2)), vec2(1, 2))
+		t.assert_eq(conversions.ivec3(ivec3(1, 2, 3)), ivec3(1, 2, 3))
+		t.assert_eq(conversions.vec4(vec4(1, 2, 3, 4)), vec4(1, 2, 3, 4))
+	end,
+	rejects_non_tables = function()
+		t.assert_error(function()
+			conversions.vec4(42)
+		end, "`Vec4` should be a table")
+		t.assert_error(function()
+			conversions.ivec2("(1, 2)")
+		end, "`IVec2` should be a table, got `string` instead")
+	end,
+	rejects_missing_fields = function()
+		t.assert_error(function()
+			conversions.vec4({ 1, 2, 3 })
+		end, "could not get `Vec4`s field `w` of type `f32`")
+		t.assert_no_error(function()
+			conversions.vec4({ 1, 2, 3, 4 })
+		end)
+	end,
+	lua_errors = function()
+		local message = t.assert_error(function()
+			error({ code = 1 })
+		end)
+		t.assert(message:find("code") ~= nil)
+	end,
+})
@@ -0,0 +1,56 @@
+local t = require("lopa-test")
+
+local function assert_near(a, b)
+	t.assert((a - b):length() < 1e-4)
+end
+
+t.test_module("curves", {
+	evaluation = function()
+		local meth = require("meth")
+		local curve = meth.curves.cubic_bezier(vec2(0, 0), vec2(0, 2), vec2(4, 2), vec2(4, 0))
+		t.assert_eq(curve:position(0.5), vec2(2, 1.5))
+		t.assert_eq(curve:derivative(0), vec2(0, 6))
+		t.assert_eq(curve:derivative(0.5), vec2(6, 0))
+		local bounds = curve:bounds()
+		assert_near(bounds.min, vec2(0, 0))
+		assert_near(bounds.max, vec2(4, 1.5))
+	end,
+	vec3 = function()
+		local meth = require("meth")
+		local curve = meth.curves.quadratic_bezier(vec3(0, 0, 0), vec3(1, 2, 0), vec3(2, 0, 0))
+		assert_near(curve:position(0.5), vec3(1, 1, 0))
+		local before, after = curve:split(0.5)
+		t.assert_eq(before:position(1), vec3(1, 1, 0))
+		t.assert_eq(after:position(0), vec3(1, 1, 0))
+		local bounds = curve:bounds()
+		assert_near(bounds.min, vec3(0, 0, 0))
+		assert_near(bounds.max, vec3(2, 1, 0))
+	end,
+	hermite_and_catmull_rom = function()
+		local meth = require("meth")
+		local hermite = meth.curves.hermite(vec2(0, 0), vec2(3, 0), vec2(1, 1), vec2(0, 3))
+		t.assert_eq(hermite:position(1), vec2(1, 1))
+		assert_near(hermite:derivative(0), vec2(3, 0))
+
+		local points = { vec2(0, 0), vec2(1, 1), vec2(2, 1), vec2(3, 0) }
+		local uniform = meth.curves.catmull_rom(points[1], points[2], points[3], points[4])
+		t.assert_eq(uniform:position(0), points[2])
+		assert_near(uniform:derivative(0), vec2(1, 0.5))
+		local centripetal = meth.curves.centripetal_catmull_rom(points[1], points[2], points[3], points[4])
+		assert_near(centripetal:position(1), points[3])
+		assert_near(centripetal:to_cubic():position(0.3), centripetal:position(0.3))
+	end,
+	arc_length = function()
+		local meth = require("meth")
+		-- a straight line that is slow at its start
+		local line = meth.curves.cubic_bezier(vec2(0, 0), vec2(0, 0), vec2(0, 0), vec2(3, 0))
+		t.assert(math.abs(line:length() - 3) < 1e-4)
+		t.assert(math.abs(line:t_at(3 / 8) - 0.5) < 1e-4)
+		assert_near(line:position_at(1.5), vec2(1.5, 0))
+		local samples = line:sample_uniform(4)
+		t.assert_eq(#samples, 4)
+		for i, sample in ipairs(samples) do
+			assert_near(sample, vec2(i - 1, 0))
+		end
+	end,
+})
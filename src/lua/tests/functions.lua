@@ -0,0 +1,57 @@
+local t = require("lopa-test")
+
+local function assert_near(a, b)
+	t.assert(math.abs(a - b) < 1e-5)
+end
+
+t.test_module("functions", {
+	shaping = function()
+		local meth = require("meth")
+		t.assert_eq(meth.sign(-3), -1)
+		t.assert_eq(meth.sign(0), 0)
+		t.assert_eq(meth.step(2, 2), 1)
+		t.assert_eq(meth.step(2, 1.9), 0)
+		t.assert_eq(meth.smoothstep(0, 1, 0.25), 0.15625)
+		t.assert_eq(meth.smoothstep(0, 2, 3), 1)
+		t.assert_eq(meth.smootherstep(0, 1, 0.5), 0.5)
+	end,
+	interpolation = function()
+		local meth = require("meth")
+		t.assert_eq(meth.mix(2, 4, 0.25), 2.5)
+		t.assert_eq(meth.inverse_lerp(4, 8, 10), 1.5)
+		t.assert_eq(meth.remap(5, 4, 8, 0, -8), -2)
+		t.assert_eq(meth.saturate(-0.5), 0)
+	end,
+	wrapping = function()
+		local meth = require("meth")
+		t.assert_eq(meth.wrap(-1, 0, 5), 4)
+		t.assert_eq(meth.wrap(12, -10, 10), -8)
+		t.assert_eq(meth.ping_pong(5, 2), 1)
+		t.assert_eq(meth.ping_pong(-1, 2), 1)
+	end,
+	easing = function()
+		local meth = require("meth")
+		for _, fun in pairs(meth.easing) do
+			assert_near(fun(0), 0)
+			assert_near(fun(1), 1)
+		end
+		t.assert_eq(meth.easing.cubic_in(0.5), 0.125)
+		assert_near(meth.easing.bounce_out(0.5), 0.765625)
+		t.assert(meth.easing.back_in(0.2) < 0)
+		t.assert(meth.easing.elastic_out(0.2) > 1)
+	end,
+	vectors = function()
+		local meth = require("meth")
+		t.assert_eq(meth.sign(vec3(-2, 0.5, 3)), vec3(-1, 1, 1))
+		t.assert_eq(meth.step(vec3(0, 1, 3), vec3(-2, 0.5, 3)), vec3(0, 0, 1))
+		t.assert_eq(meth.mix(vec2(0, 10), vec2(4, 20), vec2(0.5, 0.1)), vec2(2, 11))
+		-- numbers count for every component
+		t.assert_eq(meth.mix(vec2(0, 10), vec2(4, 20), 0.5), vec2(2, 15))
+		t.assert_eq(meth.smoothstep(0, 1, vec4(0.5)), vec4(0.5))
+		t.assert_eq(meth.wrap(ivec2(6, -1), 0, 5), ivec2(1, 4))
+		t.assert_eq(meth.easing.quad_in(vec3(0.5)), vec3(0.25))
+		local v = vec2(-1, 2)
+		meth.saturate(v)
+		t.assert_eq(v, vec2(-1, 2))
+	end,
+})
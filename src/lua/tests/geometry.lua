@@ -0,0 +1,102 @@
+local t = require("lopa-test")
+
+---@type __inner
+local inner = _G["__inner"]
+local conversions = inner.meth_conversions
+
+t.test_module("geometry", {
+	construction = function()
+		local meth = require("meth")
+		local ray = ray3(vec3(1, 2, 3), vec3(0, 0, -4))
+		t.assert_eq(ray.direction, vec3(0, 0, -1))
+		t.assert_eq(ray:at(2), vec3(1, 2, 1))
+		t.assert_eq(plane(vec3(0, 2, 0), 4), plane(vec3(0, 1, 0), 2))
+		t.assert_eq(meth.Plane.from_point_normal(vec3(5, 3, 1), vec3(0, 0, 2)), plane(vec3(0, 0, 1), 1))
+		t.assert_eq(meth.Plane.from_points(vec3(0, 0, 1), vec3(1, 0, 1), vec3(0, 1, 1)), plane(vec3(0, 0, 1), 1))
+		local s = sphere(vec3(0), 1)
+		s.radius = 2
+		t.assert_eq(s, sphere(vec3(0), 2))
+		t.assert_eq(segment2(vec2(0, 0), vec2(3, 4)):length(), 5)
+	end,
+	ray_plane = function()
+		local ground = plane(vec3(0, 1, 0), 0)
+		local hit = ray3(vec3(1, 4, 2), vec3(0, -1, 0)):intersect_plane(ground)
+		t.assert_eq(hit.distance, 4)
+		t.assert_eq(hit.point, vec3(1, 0, 2))
+		t.assert_eq(hit.normal, vec3(0, 1, 0))
+		-- from below the normal faces the ray
+		t.assert_eq(ray3(vec3(0, -1, 0), vec3(0, 1, 0)):intersect_plane(ground).normal, vec3(0, -1, 0))
+		t.assert_eq(ray3(vec3(0, 1, 0), vec3(1, 0, 0)):intersect_plane(ground), nil)
+		t.assert_eq(ray3(vec3(0, 1, 0), vec3(0, 1, 0)):intersect_plane(ground), nil)
+	end,
+	ray_sphere = function()
+		local ball = sphere(vec3(0, 0, -5), 1)
+		local hit = ray3(vec3(0), vec3(0, 0, -1)):intersect_sphere(ball)
+		t.assert_eq(hit.distance, 4)
+		t.assert_eq(hit.normal, vec3(0, 0, 1))
+		-- from inside on the way out
+		hit = ray3(vec3(0, 0, -5), vec3(0, 0, -1)):intersect_sphere(ball)
+		t.assert_eq(hit.point, vec3(0, 0, -6))
+		t.assert_eq(ray3(vec3(0), vec3(0, 0, 1)):intersect_sphere(ball), nil)
+		t.assert_eq(ray3(vec3(2, 0, 0), vec3(0, 0, -1)):intersect_sphere(ball), nil)
+	end,
+	ray_aabb = function()
+		local box = aabb3(-1, -1, -1, 1, 1, 1)
+		local hit = ray3(vec3(-5, 0, 0), vec3(1, 0, 0)):intersect_aabb(box)
+		t.assert_eq(hit.distance, 4)
+		t.assert_eq(hit.point, vec3(-1, 0, 0))
+		t.assert_eq(hit.normal, vec3(-1, 0, 0))
+		hit = ray3(vec3(0), vec3(0, 1, 0)):intersect_aabb(box)
+		t.assert_eq(hit.distance, 1)
+		t.assert_eq(hit.normal, vec3(0, 1, 0))
+		t.assert_eq(ray3(vec3(-5, 2, 0), vec3(1, 0, 0)):intersect_aabb(box), nil)
+		t.assert_eq(ray3(vec3(-5, 0, 0), vec3(-1, 0, 0)):intersect_aabb(box), nil)
+	end,
+	ray_triangle = function()
+		local a, b, c = vec3(0, 0, 0), vec3(2, 0, 0), vec3(0, 2, 0)
+		local hit = ray3(vec3(0.5, 0.5, 3), vec3(0, 0, -1)):intersect_triangle(a, b, c)
+		t.assert_eq(hit.distance, 3)
+		t.assert_eq(hit.point, vec3(0.5, 0.5, 0))
+		t.assert_eq(hit.normal, vec3(0, 0, 1))
+		hit = ray3(vec3(0.5, 0.5, -3), vec3(0, 0, 1)):intersect_triangle(a, b, c)
+		t.assert_eq(hit.normal, vec3(0, 0, -1))
+		t.assert_eq(ray3(vec3(1.5, 1.5, 3), vec3(0, 0, -1)):intersect_triangle(a, b, c), nil)
+	end,
+	ray2 = function()
+		local ray = ray2(vec2(-5, 0), vec2(1, 0))
+		local hit = ray:intersect_circle(circle(vec2(0, 0), 2))
+		t.assert_eq(hit.distance, 3)
+		t.assert_eq(hit.normal, vec2(-1, 0))
+		t.assert_eq(ray:intersect_rect(rect(-1, -1, 1, 1)).point, vec2(-1, 0))
+		hit = ray:intersect_segment(segment2(vec2(1, -1), vec2(1, 1)))
+		t.assert_eq(hit.distance, 6)
+		t.assert_eq(hit.normal, vec2(-1, 0))
+		t.assert_eq(ray:intersect_segment(segment2(vec2(1, 1), vec2(1, 2))), nil)
+	end,
+	closest_points_and_distances = function()
+		local ray = ray3(vec3(0), vec3(1, 0, 0))
+		t.assert_eq(ray:closest_point(vec3(3, 4, 0)), vec3(3, 0, 0))
+		t.assert_eq(ray:closest_point(vec3(-3, 4, 0)), vec3(0))
+		t.assert_eq(ray:distance_to_point(vec3(-3, 4, 0)), 5)
+		local ground = plane(vec3(0, 1, 0), 1)
+		t.assert_eq(ground:signed_distance(vec3(0, -2, 0)), -3)
+		t.assert_eq(ground:closest_point(vec3(4, 5, 6)), vec3(4, 1, 6))
+		local ball = sphere(vec3(0), 1)
+		t.assert(ball:contains(vec3(0, 1, 0)))
+		t.assert_eq(ball:closest_point(vec3(0, 0, 3)), vec3(0, 0, 1))
+		t.assert_eq(ball:distance_to_point(vec3(0, 0, 3)), 2)
+		t.assert_eq(ball:distance_to_point(vec3(0.5, 0, 0)), 0)
+		local segment = segment3(vec3(0), vec3(2, 0, 0))
+		t.assert_eq(segment:closest_point(vec3(4, 3, 0)), vec3(2, 0, 0))
+		t.assert_eq(segment:distance_to_point(vec3(1, 3, 0)), 3)
+	end,
+	conversions = function()
+		t.assert_eq(conversions.ray2(ray2(vec2(1, 2), vec2(0, 1))), ray2(vec2(1, 2), vec2(0, 1)))
+		t.assert_eq(conversions.ray3(ray3(vec3(1), vec3(0, 0, 1))), ray3(vec3(1), vec3(0, 0, 1)))
+		t.assert_eq(conversions.plane(plane(vec3(0, 1, 0), 2)), plane(vec3(0, 1, 0), 2))
+		t.assert_eq(conversions.sphere(sphere(vec3(1, 2, 3), 4)), sphere(vec3(1, 2, 3), 4))
+		t.assert_eq(conversions.circle(circle(vec2(1, 2), 3)), circle(vec2(1, 2), 3))
+		t.assert_eq(conversions.segment2(segment2(vec2(1, 2), vec2(3, 4))), segment2(vec2(1, 2), vec2(3, 4)))
+		t.assert_eq(conversions.segment3(segment3(vec3(1), vec3(2))), segment3(vec3(1), vec3(2)))
+	end,
+})
@@ -0,0 +1,33 @@
+local t = require("lopa-test")
+
+-- `//` and the bitwise operators only parse on Lua 5.4
+if _VERSION ~= "Lua 5.4" then
+	return
+end
+
+---@param source string
+---@return function
+local function expression(source)
+	return assert(load("local a, b = ... return " .. source))
+end
+
+t.test_module("integer", {
+	floor_division = function()
+		local idiv = expression("a // b")
+		t.assert_eq(idiv(ivec2(7, -7), 2), ivec2(3, -4))
+		t.assert_eq(idiv(ivec3(9, 8, 7), ivec3(2, 3, 4)), ivec3(4, 2, 1))
+		t.assert_eq(math.type(idiv(ivec2(7, 7), 2)[1]), "integer")
+	end,
+	bitwise = function()
+		t.assert_eq(expression("a & b")(ivec2(6, 5), 3), ivec2(2, 1))
+		t.assert_eq(expression("a | b")(ivec3(1, 2, 4), ivec3(2, 2, 1)), ivec3(3, 2, 5))
+		t.assert_eq(expression("a ~ b")(ivec4(1, 2, 3, 4), 1), ivec4(0, 3, 2, 5))
+		t.assert_eq(expression("a << b")(ivec2(1, 2), 2), ivec2(4, 8))
+		t.assert_eq(expression("a >> b")(ivec2(4, 8), 2), ivec2(1, 2))
+		t.assert_eq(expression("~a")(ivec2(0, -1)), ivec2(-1, 0))
+	end,
+	float_conversion = function()
+		local conversions = _G["__inner"].meth_conversions
+		t.assert_eq(conversions.vec3(vec3(1, 2.5, 3)), vec3(1, 2.5, 3))
+	end,
+})
@@ -0,0 +1,49 @@
+local t = require("lopa-test")
+
+---@param bytes string
+---@param index integer the 1-based index of the 4 byte word
+---@return integer
+local function word(bytes, index)
+	local a, b, c, d = string.byte(bytes, index * 4 - 3, index * 4)
+	return a + b * 0x100 + c * 0x10000 + d * 0x1000000
+end
+
+local ONE = 0x3f800000
+
+t.test_module("layout", {
+	std140 = function()
+		local meth = require("meth")
+		local bytes = meth.uniform_block("std140", {
+			{ "float", 1 },
+			{ "vec3", vec3(1, 1, 1) },
+			{ "int", 7 },
+			{ "mat3", mat3(1, 0, 0, 0, 1, 0, 0, 0, 1) },
+		})
+		t.assert_eq(#bytes, 16 + 16 + 48)
+		t.assert_eq(word(bytes, 1), ONE)
+		t.assert_eq(word(bytes, 2), 0)
+		t.assert_eq(word(bytes, 5), ONE)
+		t.assert_eq(word(bytes, 8), 7)
+		t.assert_eq(word(bytes, 9), ONE)
+		t.assert_eq(word(bytes, 12), 0)
+		t.assert_eq(word(bytes, 14), ONE)
+	end,
+	arrays = function()
+		local meth = require("meth")
+		t.assert_eq(#meth.uniform_block("std140", { { "float[]", { 1, 2, 3 } } }), 48)
+		t.assert_eq(#meth.uniform_block("std430", { { "float[]", { 1, 2, 3 } } }), 12)
+		local bytes = meth.uniform_block("std430", { { "vec3[]", { vec3(1, 1, 1), vec3(1, 1, 1) } } })
+		t.assert_eq(#bytes, 32)
+		t.assert_eq(word(bytes, 4), 0)
+		t.assert_eq(word(bytes, 5), ONE)
+	end,
+	errors = function()
+		local meth = require("meth")
+		t.assert_error(function()
+			meth.uniform_block("std999", {})
+		end, "unknown layout `std999`")
+		t.assert_error(function()
+			meth.uniform_block("std140", { { "dvec3", vec3() } })
+		end, "unknown member type `dvec3`")
+	end,
+})
@@ -0,0 +1,38 @@
+local t = require("lopa-test")
+
+if _VERSION ~= "Luau" then
+	return
+end
+
+t.test_module("luau", {
+	native_vector = function()
+		t.assert_eq(type(vec3(1, 2, 3)), "vector")
+		t.assert_eq(vec3(1, 2, 3) * 2, vec3(2, 4, 6))
+		t.assert_eq(tostring(vec3(1, 2, 3)), "(1, 2, 3)")
+	end,
+	methods = function()
+		local v = vec3(3, 4, 0)
+		t.assert_eq(v:length(), 5)
+		t.assert_eq(v[1], 3)
+		t.assert_eq(v:normalized(), vec3(0.6, 0.8, 0))
+		t.assert_eq(v % 2, vec3(1, 0, 0))
+	end,
+	in_place_methods = function()
+		local v = vec3(3, 4, 0)
+		t.assert_error(function()
+			v:normalize()
+		end, "use `Vec3:normalized` instead")
+		t.assert_error(function()
+			v:lerp(vec3(0, 0, 0), 0.5)
+		end, "use `Vec3:lerped` instead")
+		t.assert_eq(v, vec3(3, 4, 0))
+		t.assert_eq(v:clamped(vec3(0, 0, 0), vec3(1, 1, 1)), vec3(1, 1, 0))
+	end,
+	conversions = function()
+		local conversions = _G["__inner"].meth_conversions
+		t.assert_eq(conversions.vec3(vec3(1, 2, 3)), vec3(1, 2, 3))
+		t.assert_error(function()
+			conversions.vec3({ 1, 2, 3 })
+		end, "`Vec3` should be a vector")
+	end,
+})
@@ -0,0 +1,61 @@
+local t = require("lopa-test")
+
+local IDENTITY = mat3(1, 0, 0, 0, 1, 0, 0, 0, 1)
+
+t.test_module("mat3", {
+	multiplication = function()
+		t.case("matrix * vector", function()
+      -- stylua: ignore
+      local m1 = mat3(
+        1,4,7,
+        2,5,8,
+        3,6,9
+      )
+			local vec = vec3(1, 2, 3)
+			t.assert_eq(m1 * vec, vec3(14., 32., 50.))
+		end)
+		t.case("matrix * matrix", function()
+      -- stylua: ignore
+      local m1 = mat3(
+        1,4,7,
+        2,5,8,
+        3,6,9
+      )
+      -- stylua: ignore
+      local m2 = mat3(
+        9,6,3,
+        8,5,2,
+        7,4,1
+      )
+
+      -- stylua: ignore
+			t.assert_eq(m1 * m2, mat3(
+        30, 84, 138,
+        24, 69, 114,
+        18, 54, 90))
+		end)
+	end,
+	properties = function()
+		t.property("m * IDENTITY == m", { t.gen.mat3() }, function(m)
+			return m * IDENTITY == m
+		end)
+	end,
+	snapshot = function()
+    -- stylua: ignore
+    local m1 = mat3(
+      1,4,7,
+      2,5,8,
+      3,6,9
+    )
+    -- stylua: ignore
+    local m2 = mat3(
+      9,6,3,
+      8,5,2,
+      7,4,1
+    )
+		t.assert_snapshot("products", {
+			product = m1 * m2,
+			transformed = m1 * vec3(1, 2, 3),
+		})
+	end,
+})
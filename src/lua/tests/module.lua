@@ -0,0 +1,23 @@
+local t = require("lopa-test")
+
+t.test_module("module", {
+	constructors = function()
+		local meth = require("meth")
+		t.assert_eq(meth.vec3, vec3)
+		t.assert_eq(meth.ivec2(1, 2), ivec2(1, 2))
+	end,
+	definitions = function()
+		local meth = require("meth")
+		t.assert_eq(meth.Mat2.IDENTITY, mat2(1, 0, 0, 1))
+		t.assert_eq(meth.Mat3.IDENTITY, mat3(1, 0, 0, 0, 1, 0, 0, 0, 1))
+	end,
+	type_modules = function()
+		t.assert_eq(vec3(1, 2, 3), require("meth.vec3").new(1, 2, 3))
+		t.assert_eq(require("meth.mat2").IDENTITY, require("meth").Mat2.IDENTITY)
+	end,
+	helpers = function()
+		local meth = require("meth")
+		t.assert_eq(meth.clamp(5, 0, 1), 1)
+		t.assert_eq(meth.fract(1.5), 0.5)
+	end,
+})
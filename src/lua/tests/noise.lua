@@ -0,0 +1,48 @@
+local t = require("lopa-test")
+
+t.test_module("noise", {
+	seeded = function()
+		local meth = require("meth")
+		for _, kind in ipairs({ "perlin", "simplex", "value", "worley" }) do
+			local point = vec3(1.3, -2.7, 0.4)
+			local noise = meth.noise.new(kind, 7)
+			t.assert_eq(noise.seed, 7)
+			t.assert_eq(noise:sample(point), meth.noise[kind](point, 7))
+			t.assert_neq(noise:sample(point), meth.noise.new(kind, 8):sample(point))
+		end
+	end,
+	dimensions = function()
+		local meth = require("meth")
+		t.assert_eq(meth.noise.perlin(vec2(4, -2), 3), 0)
+		t.assert_eq(meth.noise.perlin(vec4(1, 2, 3, 4), 3), 0)
+		t.assert_neq(meth.noise.perlin(vec2(4.5, -2.5), 3), 0)
+		local value, gradient = meth.noise.new("simplex", 1):gradient(vec4(0.3, -1.6, 2.2, 0.45))
+		t.assert_eq(type(value), "number")
+		t.assert_eq(gradient.type, "Vec4")
+		local _, gradient = meth.noise.new("value", 1):gradient(vec2(0.3, -1.6))
+		t.assert_eq(gradient.type, "Vec2")
+	end,
+	gradients = function()
+		local meth = require("meth")
+		local noise = meth.noise.new("perlin", 5)
+		local point = vec3(-4.2, 1.75, 3.33)
+		local h = 1e-3
+		local _, gradient = noise:gradient(point)
+		local difference = (noise:sample(point + vec3(h, 0, 0)) - noise:sample(point - vec3(h, 0, 0))) / (2 * h)
+		t.assert(math.abs(difference - gradient.x) < 2e-2)
+	end,
+	fractals = function()
+		local meth = require("meth")
+		local noise = meth.noise.new("simplex", 1)
+		local point = vec2(0.37, -1.21)
+		t.assert_eq(noise:fbm(point, { octaves = 1 }), noise:sample(point))
+		t.assert_eq(noise:fbm(point), noise:fbm(point, { octaves = 5, lacunarity = 2, gain = 0.5 }))
+		local ridged, gradient = noise:ridged_gradient(point)
+		t.assert_eq(noise:ridged(point), ridged)
+		t.assert(ridged >= 0 and ridged <= 1)
+		t.assert_eq(gradient.type, "Vec2")
+		t.assert_error(function()
+			meth.noise.new("pink", 1)
+		end, "unknown noise")
+	end,
+})
@@ -0,0 +1,16 @@
+local t = require("lopa-test")
+
+t.test_module("property", {
+	looping_property_times_out = {
+		function()
+			local property = require("lopa-test.property")
+			local success, err = pcall(property.check, "loops", { t.gen.integer() }, function()
+				while true do
+				end
+			end, { seed = 1 })
+			t.assert(not success)
+			t.assert(tostring(err):find(_G["__inner"].lopa_test.timeout_prefix, 1, true) ~= nil)
+		end,
+		instructions = 100000,
+	},
+})
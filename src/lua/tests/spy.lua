@@ -0,0 +1,30 @@
+local t = require("lopa-test")
+
+local target = {
+	move = function()
+		return "real"
+	end,
+}
+
+t.test_module("spy", {
+	records_calls = function()
+		local add = t.spy(function(a, b)
+			return a + b
+		end)
+		t.assert_not_called(add)
+		t.assert_eq(add(vec2(1, 2), 3), vec2(4, 5))
+		t.assert_called(add, 1)
+		t.assert_called_with(add, vec2(1, 2), 3)
+		t.assert_eq(add.calls[1][1], vec2(1, 2))
+	end,
+	mock_replaces = function()
+		local move = t.mock(target, "move", function(position)
+			return position + vec3(1, 0, 0)
+		end)
+		t.assert_eq(target.move(vec3(1, 2, 3)), vec3(2, 2, 3))
+		t.assert_called_with(move, vec3(1, 2, 3))
+	end,
+	mock_restored = function()
+		t.assert_eq(target.move(), "real")
+	end,
+})
@@ -0,0 +1,36 @@
+local test = require("lopa-test")
+test.test_module("vec2", {
+	gc = function()
+		do
+			---@type metatable
+			local metatable = {
+				__gc = function()
+					print("collected!")
+				end,
+			}
+			local x = {}
+			setmetatable(x, metatable)
+		end
+    collectgarbage("collect")
+    collectgarbage("collect")
+	end,
+	addition = function()
+		test.case("vec + vec", function()
+			test.assert_eq(vec2(1, 1) + vec2(2, 3), vec2(3, 4))
+		end)
+		test.case("vec + num", function()
+			test.assert_eq(vec2(1, 2) + 2, vec2(3, 4))
+		end)
+		test.case("num + vec", function()
+			test.assert_eq(2 + vec2(1, 2), vec2(3, 4))
+		end)
+	end,
+	properties = function()
+		test.property("(a + b) - b ≈ a", { test.gen.vec2(), test.gen.vec2() }, function(a, b)
+			return ((a + b) - b - a):length() < 1e-9
+		end)
+		test.property("a + b == b + a", { test.gen.ivec2(), test.gen.ivec2() }, function(a, b)
+			return a + b == b + a
+		end)
+	end,
+})
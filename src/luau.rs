@@ -0,0 +1,50 @@
+//! Luau specific parts of the bindings: `Vec3` is the native `vector` value type there.
+
+use crate::vec3::Vec3;
+
+impl mlua::FromLua for Vec3 {
+    fn from_lua(value: mlua::Value, _lua: &mlua::Lua) -> mlua::Result<Self> {
+        match value {
+            mlua::Value::Vector(vector) => Ok(Vec3::new(vector.x(), vector.y(), vector.z())),
+            _ => Err(lua_error::lua_error!(
+                "`{}` should be a vector, got `{}` instead",
+                stringify!(Vec3),
+                value.type_name()
+            )),
+        }
+    }
+}
+
+impl mlua::IntoLua for Vec3 {
+    fn into_lua(self, _lua: &mlua::Lua) -> mlua::Result<mlua::Value> {
+        Ok(mlua::Value::Vector(mlua::Vector::new(
+            self.x, self.y, self.z,
+        )))
+    }
+}
+
+fn meth_table(lua: &mlua::Lua) -> mlua::Result<mlua::Table> {
+    let inner_table = lua
+        .globals()
+        .get::<Option<mlua::Table>>("__inner")?
+        .ok_or_else(|| lua_error::lua_error!("could not get __inner"))?;
+    mlua::ErrorContext::with_context(
+        inner_table.get::<mlua::Table>("meth"),
+        |_| "could not get __inner.meth table",
+    )
+}
+
+/// `__inner.meth.new_vector`, used by `vec3` when Luau's `vector` library lacks `vector.create`.
+pub(crate) fn install_vector_constructor(lua: &mlua::Lua) -> mlua::Result<()> {
+    meth_table(lua)?.set(
+        "new_vector",
+        lua.create_function(|_, (x, y, z): (f32, f32, f32)| Ok(mlua::Vector::new(x, y, z)))?,
+    )
+}
+
+/// Makes the methods of `src/lua/meth/vec3_luau.lua` reachable from every vector value.
+pub(crate) fn install_vector_metatable(lua: &mlua::Lua) -> mlua::Result<()> {
+    let metatable = meth_table(lua)?.get::<Option<mlua::Table>>("vec3_metatable")?;
+    lua.set_type_metatable::<mlua::Vector>(metatable);
+    Ok(())
+}
@@ -1,20 +1,47 @@
+mod coverage;
+mod hooks;
+mod repl;
+mod runner;
+mod script;
+mod watch;
+
 use meth::apply;
 use mlua::Lua;
 use std::env;
 
 fn main() -> mlua::Result<()> {
-    //TODO: check windows support
-    unsafe { env::set_var("LUA_PATH", "?.lua;?/mod.lua") };
-    let mut lua = Lua::new();
-    apply(&mut lua).unwrap();
-
-    lopa_test::apply(&lua)?;
     let args: Vec<_> = env::args().collect();
     if let Some(second) = args.get(1)
         && second == "--test"
     {
-        lopa_test::execute_tests("src.lua.lopa-test", &lua)?;
+        let options = runner::Options::from_args(&args);
+        if options.watch {
+            return watch::run(&options);
+        }
+        if !runner::run(&options)? {
+            std::process::exit(1);
+        }
+        return Ok(());
     }
 
+    if let Some(second) = args.get(1)
+        && second == "repl"
+    {
+        return repl::run();
+    }
+    if let Some(second) = args.get(1)
+        && second == "run"
+    {
+        let Some(options) = script::Options::from_args(&args[2..]) else {
+            eprintln!("{}", script::USAGE);
+            std::process::exit(2);
+        };
+        if !script::run(&args[0], &options)? {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+    let mut lua = Lua::new();
+    apply(&mut lua).unwrap();
     Ok(())
 }
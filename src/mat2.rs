@@ -1,8 +1,9 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 use crate::vec2::Vec2;
 
-#[derive(Default, Debug, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[derive(Default, Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
 pub struct Mat2 {
     pub x_axis: Vec2,
     pub y_axis: Vec2,
@@ -23,8 +24,16 @@ impl Mat2 {
     pub const fn splat(value: f32) -> Self {
         Self::from_axis(Vec2::splat(value), Vec2::splat(value))
     }
+    /// Column-major, the columns `x_axis`, `y_axis`, ... one after another.
+    pub const fn from_cols_array(array: &[f32; 4]) -> Self {
+        Self::new(array[0], array[1], array[2], array[3])
+    }
+    /// Column-major, the inverse of [`Self::from_cols_array`].
+    pub const fn to_cols_array(&self) -> [f32; 4] {
+        [self.x_axis.x, self.x_axis.y, self.y_axis.x, self.y_axis.y]
+    }
     pub fn from_angle(angle: f32) -> Self {
-        let (sin, cos) = f32::sin_cos(angle);
+        let (sin, cos) = crate::math::sin_cos(angle);
         Self::new(cos, -sin, sin, cos)
     }
 }
@@ -180,6 +189,7 @@ impl DivAssign<f32> for Mat2 {
     }
 }
 
+#[cfg(feature = "lua")]
 impl mlua::FromLua for Mat2 {
     fn from_lua(value: mlua::Value, _lua: &mlua::Lua) -> mlua::Result<Self> {
         let table = value.as_table().ok_or_else(|| {
@@ -210,6 +220,7 @@ impl mlua::FromLua for Mat2 {
     }
 }
 
+#[cfg(feature = "lua")]
 impl mlua::IntoLua for Mat2 {
     fn into_lua(self, lua: &mlua::Lua) -> mlua::Result<mlua::Value> {
         let table = lua.create_table()?;
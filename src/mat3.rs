@@ -1,8 +1,9 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 use crate::{vec2::Vec2, vec3::Vec3};
 
-#[derive(Debug, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize, Default)]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[repr(C)]
 pub struct Mat3 {
     pub x_axis: Vec3,
     pub y_axis: Vec3,
@@ -20,8 +21,30 @@ impl Mat3 {
     pub const fn splat(value: f32) -> Self {
         Self::from_axis(Vec3::splat(value), Vec3::splat(value), Vec3::splat(value))
     }
+    /// Column-major, the columns `x_axis`, `y_axis`, ... one after another.
+    pub const fn from_cols_array(array: &[f32; 9]) -> Self {
+        Self::from_axis(
+            Vec3::new(array[0], array[1], array[2]),
+            Vec3::new(array[3], array[4], array[5]),
+            Vec3::new(array[6], array[7], array[8]),
+        )
+    }
+    /// Column-major, the inverse of [`Self::from_cols_array`].
+    pub const fn to_cols_array(&self) -> [f32; 9] {
+        [
+            self.x_axis.x,
+            self.x_axis.y,
+            self.x_axis.z,
+            self.y_axis.x,
+            self.y_axis.y,
+            self.y_axis.z,
+            self.z_axis.x,
+            self.z_axis.y,
+            self.z_axis.z,
+        ]
+    }
     pub fn from_rotation_x(angle: f32) -> Self {
-        let (sin, cos) = angle.sin_cos();
+        let (sin, cos) = crate::math::sin_cos(angle);
         Self::from_axis(
             Vec3::new(1., 0., 0.),
             Vec3::new(0., cos, -sin),
@@ -29,7 +52,7 @@ impl Mat3 {
         )
     }
     pub fn from_rotation_y(angle: f32) -> Self {
-        let (sin, cos) = angle.sin_cos();
+        let (sin, cos) = crate::math::sin_cos(angle);
         Self::from_axis(
             Vec3::new(cos, 0., sin),
             Vec3::new(0., 1., 0.),
@@ -37,13 +60,15 @@ impl Mat3 {
         )
     }
     pub fn from_rotation_z(angle: f32) -> Self {
-        let (sin, cos) = angle.sin_cos();
+        let (sin, cos) = crate::math::sin_cos(angle);
         Self::from_axis(
             Vec3::new(cos, -sin, 0.),
             Vec3::new(sin, cos, 0.),
             Vec3::new(0., 0., 1.),
         )
     }
+    /// The translation ends up in the bottom row, like the rotations this is the transpose of the
+    /// column-vector matrix `glam::Mat3::from_translation` builds.
     pub fn from_translation(position: Vec2) -> Self {
         Self::from_axis(
             Vec3::new(1., 0., position.x),
@@ -252,6 +277,7 @@ impl Mul<Vec3> for Mat3 {
     }
 }
 
+#[cfg(feature = "lua")]
 impl mlua::FromLua for Mat3 {
     fn from_lua(value: mlua::Value, _lua: &mlua::Lua) -> mlua::Result<Self> {
         let table = value.as_table().ok_or_else(|| {
@@ -299,6 +325,7 @@ impl mlua::FromLua for Mat3 {
     }
 }
 
+#[cfg(feature = "lua")]
 impl mlua::IntoLua for Mat3 {
     fn into_lua(self, lua: &mlua::Lua) -> mlua::Result<mlua::Value> {
         let table = lua.create_table()?;
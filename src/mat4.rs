@@ -1,8 +1,9 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 use crate::vec4::Vec4;
 
-#[derive(Debug, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize, Default)]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+#[repr(C)]
 pub struct Mat4 {
     pub x_axis: Vec4,
     pub y_axis: Vec4,
@@ -27,6 +28,36 @@ impl Mat4 {
             Vec4::splat(value),
         )
     }
+    /// Column-major, the columns `x_axis`, `y_axis`, ... one after another.
+    pub const fn from_cols_array(array: &[f32; 16]) -> Self {
+        Self::from_axis(
+            Vec4::new(array[0], array[1], array[2], array[3]),
+            Vec4::new(array[4], array[5], array[6], array[7]),
+            Vec4::new(array[8], array[9], array[10], array[11]),
+            Vec4::new(array[12], array[13], array[14], array[15]),
+        )
+    }
+    /// Column-major, the inverse of [`Self::from_cols_array`].
+    pub const fn to_cols_array(&self) -> [f32; 16] {
+        [
+            self.x_axis.x,
+            self.x_axis.y,
+            self.x_axis.z,
+            self.x_axis.w,
+            self.y_axis.x,
+            self.y_axis.y,
+            self.y_axis.z,
+            self.y_axis.w,
+            self.z_axis.x,
+            self.z_axis.y,
+            self.z_axis.z,
+            self.z_axis.w,
+            self.w_axis.x,
+            self.w_axis.y,
+            self.w_axis.z,
+            self.w_axis.w,
+        ]
+    }
 }
 
 impl Add for Mat4 {
@@ -257,6 +288,7 @@ impl Mul<Vec4> for Mat4 {
     }
 }
 
+#[cfg(feature = "lua")]
 impl mlua::FromLua for Mat4 {
     fn from_lua(value: mlua::Value, _lua: &mlua::Lua) -> mlua::Result<Self> {
         let table = value.as_table().ok_or_else(|| {
@@ -327,6 +359,7 @@ impl mlua::FromLua for Mat4 {
     }
 }
 
+#[cfg(feature = "lua")]
 impl mlua::IntoLua for Mat4 {
     fn into_lua(self, lua: &mlua::Lua) -> mlua::Result<mlua::Value> {
         let table = lua.create_table()?;
@@ -0,0 +1,71 @@
+//! Float functions of the math types, from std or from libm on `no_std` builds.
+
+#[cfg(feature = "std")]
+mod imp {
+    pub fn sqrt(value: f32) -> f32 {
+        value.sqrt()
+    }
+    pub fn floor(value: f32) -> f32 {
+        value.floor()
+    }
+    pub fn ceil(value: f32) -> f32 {
+        value.ceil()
+    }
+    pub fn trunc(value: f32) -> f32 {
+        value.trunc()
+    }
+    pub fn round(value: f32) -> f32 {
+        value.round()
+    }
+    pub fn abs(value: f32) -> f32 {
+        value.abs()
+    }
+    pub fn sin_cos(value: f32) -> (f32, f32) {
+        value.sin_cos()
+    }
+    pub fn sin(value: f32) -> f32 {
+        value.sin()
+    }
+    pub fn exp2(value: f32) -> f32 {
+        value.exp2()
+    }
+    pub fn powf(value: f32, exponent: f32) -> f32 {
+        value.powf(exponent)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod imp {
+    pub fn sqrt(value: f32) -> f32 {
+        libm::sqrtf(value)
+    }
+    pub fn floor(value: f32) -> f32 {
+        libm::floorf(value)
+    }
+    pub fn ceil(value: f32) -> f32 {
+        libm::ceilf(value)
+    }
+    pub fn trunc(value: f32) -> f32 {
+        libm::truncf(value)
+    }
+    pub fn round(value: f32) -> f32 {
+        libm::roundf(value)
+    }
+    pub fn abs(value: f32) -> f32 {
+        libm::fabsf(value)
+    }
+    pub fn sin_cos(value: f32) -> (f32, f32) {
+        libm::sincosf(value)
+    }
+    pub fn sin(value: f32) -> f32 {
+        libm::sinf(value)
+    }
+    pub fn exp2(value: f32) -> f32 {
+        libm::exp2f(value)
+    }
+    pub fn powf(value: f32, exponent: f32) -> f32 {
+        libm::powf(value, exponent)
+    }
+}
+
+pub(crate) use imp::*;
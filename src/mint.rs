@@ -0,0 +1,127 @@
+//! Conversions from and to the [`mint`](https://docs.rs/mint) types, the matrices as
+//! `ColumnMatrix*` so `x_axis` becomes the column `x`.
+
+use crate::{
+    mat2::Mat2,
+    mat3::Mat3,
+    mat4::Mat4,
+    vec2::{IVec2, UVec2, Vec2},
+    vec3::{IVec3, UVec3, Vec3},
+    vec4::{IVec4, UVec4, Vec4},
+};
+
+macro_rules! impl_vector {
+    ($name:ident, $mint:ident<$scalar:ty>, [$($field:ident),+]) => {
+        impl From<$name> for ::mint::$mint<$scalar> {
+            fn from(value: $name) -> Self {
+                Self { $($field: value.$field),+ }
+            }
+        }
+
+        impl From<::mint::$mint<$scalar>> for $name {
+            fn from(value: ::mint::$mint<$scalar>) -> Self {
+                Self { $($field: value.$field),+ }
+            }
+        }
+
+        impl ::mint::IntoMint for $name {
+            type MintType = ::mint::$mint<$scalar>;
+        }
+    };
+}
+
+macro_rules! impl_matrix {
+    ($name:ident, $mint:ident, [$($column:ident: $axis:ident),+]) => {
+        impl From<$name> for ::mint::$mint<f32> {
+            fn from(value: $name) -> Self {
+                Self { $($column: value.$axis.into()),+ }
+            }
+        }
+
+        impl From<::mint::$mint<f32>> for $name {
+            fn from(value: ::mint::$mint<f32>) -> Self {
+                Self { $($axis: value.$column.into()),+ }
+            }
+        }
+
+        impl ::mint::IntoMint for $name {
+            type MintType = ::mint::$mint<f32>;
+        }
+    };
+}
+
+impl_vector!(UVec2, Vector2<u32>, [x, y]);
+impl_vector!(IVec2, Vector2<i32>, [x, y]);
+impl_vector!(Vec2, Vector2<f32>, [x, y]);
+impl_vector!(UVec3, Vector3<u32>, [x, y, z]);
+impl_vector!(IVec3, Vector3<i32>, [x, y, z]);
+impl_vector!(Vec3, Vector3<f32>, [x, y, z]);
+impl_vector!(UVec4, Vector4<u32>, [x, y, z, w]);
+impl_vector!(IVec4, Vector4<i32>, [x, y, z, w]);
+impl_vector!(Vec4, Vector4<f32>, [x, y, z, w]);
+
+impl_matrix!(Mat2, ColumnMatrix2, [x: x_axis, y: y_axis]);
+impl_matrix!(Mat3, ColumnMatrix3, [x: x_axis, y: y_axis, z: z_axis]);
+impl_matrix!(Mat4, ColumnMatrix4, [x: x_axis, y: y_axis, z: z_axis, w: w_axis]);
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        mat3::Mat3,
+        vec2::IVec2,
+        vec3::Vec3,
+        vec4::{UVec4, Vec4},
+    };
+
+    #[test]
+    fn vectors() {
+        let vector: mint::Vector3<f32> = Vec3::new(1., 2., 3.).into();
+        assert_eq!(
+            vector,
+            mint::Vector3 {
+                x: 1.,
+                y: 2.,
+                z: 3.
+            }
+        );
+        assert_eq!(Vec3::from(vector), Vec3::new(1., 2., 3.));
+        assert_eq!(
+            IVec2::from(mint::Vector2 { x: -1, y: 2 }),
+            IVec2::new(-1, 2)
+        );
+        let vector: mint::Vector4<u32> = UVec4::new(1, 2, 3, 4).into();
+        assert_eq!(vector.w, 4);
+        assert_eq!(mint::Vector4::from(Vec4::splat(1.)).w, 1.);
+    }
+
+    #[test]
+    fn matrices_are_column_major() {
+        let matrix = Mat3::from_cols_array(&[1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let columns: mint::ColumnMatrix3<f32> = matrix.into();
+        assert_eq!(
+            columns.x,
+            mint::Vector3 {
+                x: 1.,
+                y: 2.,
+                z: 3.
+            }
+        );
+        assert_eq!(
+            columns.y,
+            mint::Vector3 {
+                x: 4.,
+                y: 5.,
+                z: 6.
+            }
+        );
+        assert_eq!(
+            columns.z,
+            mint::Vector3 {
+                x: 7.,
+                y: 8.,
+                z: 9.
+            }
+        );
+        assert_eq!(Mat3::from(columns), matrix);
+    }
+}
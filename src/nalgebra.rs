@@ -0,0 +1,117 @@
+//! Conversions from and to the [`nalgebra`](https://docs.rs/nalgebra) types. nalgebra stores
+//! matrices column-major too, `x_axis` is `column(0)`.
+
+use crate::{
+    mat2::Mat2,
+    mat3::Mat3,
+    mat4::Mat4,
+    vec2::{IVec2, UVec2, Vec2},
+    vec3::{IVec3, UVec3, Vec3},
+    vec4::{IVec4, UVec4, Vec4},
+};
+
+macro_rules! impl_vector {
+    ($name:ident, $nalgebra:ident<$scalar:ty>, [$($field:ident),+]) => {
+        impl From<$name> for ::nalgebra::$nalgebra<$scalar> {
+            fn from(value: $name) -> Self {
+                Self::new($(value.$field),+)
+            }
+        }
+
+        impl From<::nalgebra::$nalgebra<$scalar>> for $name {
+            fn from(value: ::nalgebra::$nalgebra<$scalar>) -> Self {
+                Self::new($(value.$field),+)
+            }
+        }
+    };
+}
+
+macro_rules! impl_matrix {
+    ($name:ident, $nalgebra:ident, $len:literal) => {
+        impl From<$name> for ::nalgebra::$nalgebra<f32> {
+            fn from(value: $name) -> Self {
+                Self::from_column_slice(&value.to_cols_array())
+            }
+        }
+
+        impl From<::nalgebra::$nalgebra<f32>> for $name {
+            fn from(value: ::nalgebra::$nalgebra<f32>) -> Self {
+                let mut array = [0.; $len];
+                array.copy_from_slice(value.as_slice());
+                Self::from_cols_array(&array)
+            }
+        }
+    };
+}
+
+impl_vector!(UVec2, Vector2<u32>, [x, y]);
+impl_vector!(IVec2, Vector2<i32>, [x, y]);
+impl_vector!(Vec2, Vector2<f32>, [x, y]);
+impl_vector!(UVec3, Vector3<u32>, [x, y, z]);
+impl_vector!(IVec3, Vector3<i32>, [x, y, z]);
+impl_vector!(Vec3, Vector3<f32>, [x, y, z]);
+impl_vector!(UVec4, Vector4<u32>, [x, y, z, w]);
+impl_vector!(IVec4, Vector4<i32>, [x, y, z, w]);
+impl_vector!(Vec4, Vector4<f32>, [x, y, z, w]);
+
+impl_matrix!(Mat2, Matrix2, 4);
+impl_matrix!(Mat3, Matrix3, 9);
+impl_matrix!(Mat4, Matrix4, 16);
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        mat2::Mat2,
+        mat3::Mat3,
+        vec2::{IVec2, Vec2},
+        vec3::Vec3,
+        vec4::UVec4,
+    };
+
+    #[test]
+    fn vectors() {
+        assert_eq!(
+            nalgebra::Vector3::from(Vec3::new(1., 2., 3.)),
+            nalgebra::Vector3::new(1., 2., 3.)
+        );
+        assert_eq!(
+            IVec2::from(nalgebra::Vector2::new(-1, 2)),
+            IVec2::new(-1, 2)
+        );
+        assert_eq!(
+            UVec4::from(nalgebra::Vector4::new(1, 2, 3, 4)),
+            UVec4::new(1, 2, 3, 4)
+        );
+    }
+
+    #[test]
+    fn matrices_are_column_major() {
+        let matrix = Mat3::from_cols_array(&[1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let nalgebra_matrix = nalgebra::Matrix3::from(matrix);
+        assert_eq!(
+            nalgebra_matrix.column(0),
+            nalgebra::Vector3::new(1., 2., 3.)
+        );
+        assert_eq!(nalgebra_matrix[(0, 1)], 4.);
+        assert_eq!(nalgebra_matrix[(2, 0)], 3.);
+        assert_eq!(Mat3::from(nalgebra_matrix), matrix);
+
+        let vector = Vec3::new(1., -2., 3.);
+        assert_eq!(
+            Vec3::from(nalgebra_matrix * nalgebra::Vector3::from(vector)),
+            matrix * vector
+        );
+        let other = Mat3::from_cols_array(&[9., 8., 7., 6., 5., 4., 3., 2., 1.]);
+        assert_eq!(
+            Mat3::from(nalgebra_matrix * nalgebra::Matrix3::from(other)),
+            matrix * other
+        );
+
+        let matrix = Mat2::new(1., 2., 3., 4.);
+        let vector = Vec2::new(5., 6.);
+        assert_eq!(
+            Vec2::from(nalgebra::Matrix2::from(matrix) * nalgebra::Vector2::from(vector)),
+            matrix * vector
+        );
+    }
+}
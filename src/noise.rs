@@ -0,0 +1,541 @@
+//! Seeded coherent noise over [`Vec2`], [`Vec3`] and [`Vec4`] with analytic gradients.
+//!
+//! The Lua module calls into these functions, so a seed gives the same noise in both. Perlin,
+//! simplex and value noise are mostly within `-1..=1`, Worley noise is the distance to the
+//! closest feature point.
+
+use crate::{math, vec2::Vec2, vec3::Vec3, vec4::Vec4};
+
+/// Brings Perlin noise to roughly `-1..=1`, measured on random samples.
+const PERLIN_SCALE: f32 = 1.25;
+/// The same for simplex noise.
+const SIMPLEX_SCALE: f32 = 64.;
+/// The squared radius of the simplex corners' influence, small enough to stay continuous.
+const SIMPLEX_RADIUS_SQUARED: f32 = 0.5;
+
+/// The vectors noise takes, as arrays of their `N` components.
+pub trait Input<const N: usize>: Copy {
+    fn to_array(self) -> [f32; N];
+    fn from_array(array: [f32; N]) -> Self;
+}
+
+macro_rules! impl_input {
+    ($name:ident, $dimensions:literal, [$($field:ident),+]) => {
+        impl Input<$dimensions> for $name {
+            fn to_array(self) -> [f32; $dimensions] {
+                [$(self.$field),+]
+            }
+            fn from_array([$($field),+]: [f32; $dimensions]) -> Self {
+                $name { $($field),+ }
+            }
+        }
+    };
+}
+
+impl_input!(Vec2, 2, [x, y]);
+impl_input!(Vec3, 3, [x, y, z]);
+impl_input!(Vec4, 4, [x, y, z, w]);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Kind {
+    /// Gradient noise on a square grid.
+    Perlin,
+    /// Gradient noise on a simplex grid, with fewer axis aligned artifacts than Perlin.
+    Simplex,
+    /// Random values on a square grid, smoothly interpolated.
+    Value,
+    /// The distance to the closest of one random point per grid cell.
+    Worley,
+}
+
+/// Octaves summed by the fractal noises, each `lacunarity` times the frequency and `gain` times
+/// the amplitude of the one before.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Fractal {
+    pub octaves: u32,
+    pub lacunarity: f32,
+    pub gain: f32,
+}
+
+impl Default for Fractal {
+    fn default() -> Self {
+        Self {
+            octaves: 5,
+            lacunarity: 2.,
+            gain: 0.5,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Noise {
+    pub kind: Kind,
+    pub seed: u32,
+}
+
+impl Noise {
+    pub const fn new(kind: Kind, seed: u32) -> Self {
+        Self { kind, seed }
+    }
+    pub fn sample<const N: usize, P: Input<N>>(self, point: P) -> f32 {
+        self.gradient(point).0
+    }
+    /// The value at `point` and its gradient there.
+    pub fn gradient<const N: usize, P: Input<N>>(self, point: P) -> (f32, P) {
+        let (value, gradient) = self.sample_array(self.seed, point.to_array());
+        (value, P::from_array(gradient))
+    }
+    /// Fractal Brownian motion, the octaves summed and divided by the sum of their amplitudes.
+    pub fn fbm<const N: usize, P: Input<N>>(self, point: P, fractal: Fractal) -> f32 {
+        self.fbm_gradient(point, fractal).0
+    }
+    pub fn fbm_gradient<const N: usize, P: Input<N>>(self, point: P, fractal: Fractal) -> (f32, P) {
+        self.octaves(point, fractal, |value, derivative| (value, derivative))
+    }
+    /// Like [`Self::fbm`] with the octaves folded to `(1 - |value|)²`, sharp ridges in `0..=1`
+    /// where the noise crosses zero.
+    pub fn ridged<const N: usize, P: Input<N>>(self, point: P, fractal: Fractal) -> f32 {
+        self.ridged_gradient(point, fractal).0
+    }
+    pub fn ridged_gradient<const N: usize, P: Input<N>>(
+        self,
+        point: P,
+        fractal: Fractal,
+    ) -> (f32, P) {
+        self.octaves(point, fractal, |value, derivative| {
+            let ridge = 1. - math::abs(value);
+            let slope = -2. * ridge * if value < 0. { -1. } else { 1. };
+            (ridge * ridge, derivative * slope)
+        })
+    }
+
+    /// Sums the octaves after passing each through `fold`, which gets the value and the
+    /// derivative of the noise along one axis at a time.
+    fn octaves<const N: usize, P: Input<N>>(
+        self,
+        point: P,
+        fractal: Fractal,
+        fold: impl Fn(f32, f32) -> (f32, f32),
+    ) -> (f32, P) {
+        let point = point.to_array();
+        let mut value = 0.;
+        let mut gradient = [0.; N];
+        let mut frequency = 1.;
+        let mut amplitude = 1.;
+        let mut amplitudes = 0.;
+        for octave in 0..fractal.octaves {
+            let (octave_value, octave_gradient) =
+                self.sample_array(self.seed.wrapping_add(octave), point.map(|x| x * frequency));
+            for (sum, derivative) in gradient.iter_mut().zip(octave_gradient) {
+                let (_, derivative) = fold(octave_value, derivative);
+                *sum += amplitude * frequency * derivative;
+            }
+            value += amplitude * fold(octave_value, 0.).0;
+            amplitudes += amplitude;
+            frequency *= fractal.lacunarity;
+            amplitude *= fractal.gain;
+        }
+        if amplitudes == 0. {
+            return (0., P::from_array(gradient));
+        }
+        (
+            value / amplitudes,
+            P::from_array(gradient.map(|derivative| derivative / amplitudes)),
+        )
+    }
+    fn sample_array<const N: usize>(self, seed: u32, point: [f32; N]) -> (f32, [f32; N]) {
+        match self.kind {
+            Kind::Perlin => perlin(seed, point),
+            Kind::Simplex => simplex(seed, point),
+            Kind::Value => value(seed, point),
+            Kind::Worley => worley(seed, point),
+        }
+    }
+}
+
+/// A well distributed 32 bit hash, "lowbias32" by Chris Wellons.
+fn mix(mut x: u32) -> u32 {
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7feb_352d);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x846c_a68b);
+    x ^= x >> 16;
+    x
+}
+
+fn hash<const N: usize>(seed: u32, cell: [i32; N]) -> u32 {
+    cell.iter()
+        .fold(mix(seed), |hash, &coordinate| mix(hash ^ coordinate as u32))
+}
+
+/// In `-1..1`.
+fn signed(hash: u32) -> f32 {
+    (hash >> 8) as f32 / (1 << 23) as f32 - 1.
+}
+
+/// In `0..1`.
+fn unsigned(hash: u32) -> f32 {
+    (hash >> 8) as f32 / (1 << 24) as f32
+}
+
+/// A random vector from the hash of a cell, `to_float` of a further hash per component.
+fn random_vector<const N: usize>(hash: u32, to_float: fn(u32) -> f32) -> [f32; N] {
+    core::array::from_fn(|index| {
+        to_float(mix(hash ^ 0x9e37_79b9u32.wrapping_mul(index as u32 + 1)))
+    })
+}
+
+fn dot<const N: usize>(a: [f32; N], b: [f32; N]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6. - 15.) + 10.)
+}
+
+fn fade_derivative(t: f32) -> f32 {
+    30. * t * t * (t * (t - 2.) + 1.)
+}
+
+/// Interpolates `corner(cell, offset)` between the `2^N` corners of the grid cell around `point`,
+/// `corner` returns the value and gradient of the corner's own function at `point`.
+fn interpolate<const N: usize>(
+    point: [f32; N],
+    corner: impl Fn([i32; N], [f32; N]) -> (f32, [f32; N]),
+) -> (f32, [f32; N]) {
+    let cell = point.map(|x| math::floor(x) as i32);
+    let fraction: [f32; N] = core::array::from_fn(|i| point[i] - cell[i] as f32);
+    let mut value = 0.;
+    let mut gradient = [0.; N];
+    for bits in 0..1u32 << N {
+        let corner_cell: [i32; N] = core::array::from_fn(|i| cell[i] + ((bits >> i) & 1) as i32);
+        let offset: [f32; N] = core::array::from_fn(|i| fraction[i] - ((bits >> i) & 1) as f32);
+        // the weight is the product of a fade or its opposite per axis
+        let weights: [f32; N] = core::array::from_fn(|i| {
+            let fade = fade(fraction[i]);
+            if (bits >> i) & 1 == 1 {
+                fade
+            } else {
+                1. - fade
+            }
+        });
+        let weight: f32 = weights.iter().product();
+        let (corner_value, corner_gradient) = corner(corner_cell, offset);
+        value += weight * corner_value;
+        for (i, derivative) in gradient.iter_mut().enumerate() {
+            let others: f32 = (0..N).filter(|&j| j != i).map(|j| weights[j]).product();
+            let sign = if (bits >> i) & 1 == 1 { 1. } else { -1. };
+            *derivative += sign * fade_derivative(fraction[i]) * others * corner_value
+                + weight * corner_gradient[i];
+        }
+    }
+    (value, gradient)
+}
+
+fn perlin<const N: usize>(seed: u32, point: [f32; N]) -> (f32, [f32; N]) {
+    let (value, gradient) = interpolate(point, |cell, offset| {
+        let direction = random_vector(hash(seed, cell), signed);
+        (dot(direction, offset), direction)
+    });
+    (value * PERLIN_SCALE, gradient.map(|x| x * PERLIN_SCALE))
+}
+
+fn value<const N: usize>(seed: u32, point: [f32; N]) -> (f32, [f32; N]) {
+    interpolate(point, |cell, _| (signed(hash(seed, cell)), [0.; N]))
+}
+
+fn simplex<const N: usize>(seed: u32, point: [f32; N]) -> (f32, [f32; N]) {
+    let dimensions = N as f32;
+    let skew = (math::sqrt(dimensions + 1.) - 1.) / dimensions;
+    let unskew = (1. - 1. / math::sqrt(dimensions + 1.)) / dimensions;
+
+    let skewed = point.iter().sum::<f32>() * skew;
+    let cell = point.map(|x| math::floor(x + skewed) as i32);
+    let unskewed = cell.iter().map(|&c| c as f32).sum::<f32>() * unskew;
+    let first: [f32; N] = core::array::from_fn(|i| point[i] - (cell[i] as f32 - unskewed));
+    // the simplex steps one axis at a time, largest offset first
+    let mut order: [usize; N] = core::array::from_fn(|i| i);
+    order.sort_unstable_by(|&a, &b| first[b].total_cmp(&first[a]).then(a.cmp(&b)));
+
+    let mut value = 0.;
+    let mut gradient = [0.; N];
+    let mut step = [0; N];
+    for corner in 0..=N {
+        if corner > 0 {
+            step[order[corner - 1]] = 1;
+        }
+        let offset: [f32; N] =
+            core::array::from_fn(|i| first[i] - step[i] as f32 + corner as f32 * unskew);
+        let falloff = SIMPLEX_RADIUS_SQUARED - dot(offset, offset);
+        if falloff <= 0. {
+            continue;
+        }
+        let direction = random_vector(
+            hash::<N>(seed, core::array::from_fn(|i| cell[i] + step[i])),
+            signed,
+        );
+        let projection = dot(direction, offset);
+        let falloff2 = falloff * falloff;
+        let falloff4 = falloff2 * falloff2;
+        value += falloff4 * projection;
+        for (i, derivative) in gradient.iter_mut().enumerate() {
+            *derivative +=
+                falloff4 * direction[i] - 8. * falloff2 * falloff * projection * offset[i];
+        }
+    }
+    (value * SIMPLEX_SCALE, gradient.map(|x| x * SIMPLEX_SCALE))
+}
+
+fn worley<const N: usize>(seed: u32, point: [f32; N]) -> (f32, [f32; N]) {
+    let cell = point.map(|x| math::floor(x) as i32);
+    let mut closest = f32::INFINITY;
+    let mut offset = [0.; N];
+    // with one point per cell the closest one is in a neighbouring cell at most
+    for neighbour in 0..3u32.pow(N as u32) {
+        let neighbour_cell: [i32; N] =
+            core::array::from_fn(|i| cell[i] + (neighbour / 3u32.pow(i as u32) % 3) as i32 - 1);
+        let feature: [f32; N] = random_vector(hash(seed, neighbour_cell), unsigned);
+        let candidate: [f32; N] =
+            core::array::from_fn(|i| point[i] - (neighbour_cell[i] as f32 + feature[i]));
+        let distance = dot(candidate, candidate);
+        if distance < closest {
+            closest = distance;
+            offset = candidate;
+        }
+    }
+    let distance = math::sqrt(closest);
+    if distance == 0. {
+        return (0., [0.; N]);
+    }
+    (distance, offset.map(|x| x / distance))
+}
+
+#[cfg(feature = "lua")]
+mod lua {
+    use mlua::{FromLua, IntoLuaMulti};
+
+    use super::*;
+
+    /// Calls `$body` with `$point` converted to the vector type of its length.
+    macro_rules! with_point {
+        ($lua:expr, $point:expr, |$name:ident| $body:expr) => {{
+            let dimensions = match &$point {
+                mlua::Value::Table(point) => point.len()?,
+                #[cfg(feature = "luau")]
+                mlua::Value::Vector(_) => 3,
+                _ => 2,
+            };
+            match dimensions {
+                4 => {
+                    let $name = Vec4::from_lua($point, $lua)?;
+                    $body.into_lua_multi($lua)
+                }
+                3 => {
+                    let $name = Vec3::from_lua($point, $lua)?;
+                    $body.into_lua_multi($lua)
+                }
+                _ => {
+                    let $name = Vec2::from_lua($point, $lua)?;
+                    $body.into_lua_multi($lua)
+                }
+            }
+        }};
+    }
+
+    fn kind(name: &str) -> mlua::Result<Kind> {
+        match name {
+            "perlin" => Ok(Kind::Perlin),
+            "simplex" => Ok(Kind::Simplex),
+            "value" => Ok(Kind::Value),
+            "worley" => Ok(Kind::Worley),
+            _ => Err(lua_error::lua_error!(
+                "unknown noise `{name}`, expected `perlin`, `simplex`, `value` or `worley`"
+            )),
+        }
+    }
+
+    /// `{ octaves = 5, lacunarity = 2, gain = 0.5 }`, missing fields keep their defaults.
+    fn fractal(table: Option<mlua::Table>) -> mlua::Result<Fractal> {
+        let mut fractal = Fractal::default();
+        if let Some(table) = table {
+            fractal.octaves = table
+                .get::<Option<u32>>("octaves")?
+                .unwrap_or(fractal.octaves);
+            fractal.lacunarity = table
+                .get::<Option<f32>>("lacunarity")?
+                .unwrap_or(fractal.lacunarity);
+            fractal.gain = table.get::<Option<f32>>("gain")?.unwrap_or(fractal.gain);
+        }
+        Ok(fractal)
+    }
+
+    impl mlua::UserData for Noise {
+        fn add_fields<F: mlua::UserDataFields<Self>>(fields: &mut F) {
+            fields.add_field_method_get("seed", |_, this| Ok(this.seed));
+        }
+        fn add_methods<M: mlua::UserDataMethods<Self>>(methods: &mut M) {
+            methods.add_method("sample", |lua, this, point: mlua::Value| {
+                with_point!(lua, point, |point| this.sample(point))
+            });
+            methods.add_method("gradient", |lua, this, point: mlua::Value| {
+                with_point!(lua, point, |point| this.gradient(point))
+            });
+            methods.add_method(
+                "fbm",
+                |lua, this, (point, table): (mlua::Value, Option<mlua::Table>)| {
+                    let fractal = fractal(table)?;
+                    with_point!(lua, point, |point| this.fbm(point, fractal))
+                },
+            );
+            methods.add_method(
+                "fbm_gradient",
+                |lua, this, (point, table): (mlua::Value, Option<mlua::Table>)| {
+                    let fractal = fractal(table)?;
+                    with_point!(lua, point, |point| this.fbm_gradient(point, fractal))
+                },
+            );
+            methods.add_method(
+                "ridged",
+                |lua, this, (point, table): (mlua::Value, Option<mlua::Table>)| {
+                    let fractal = fractal(table)?;
+                    with_point!(lua, point, |point| this.ridged(point, fractal))
+                },
+            );
+            methods.add_method(
+                "ridged_gradient",
+                |lua, this, (point, table): (mlua::Value, Option<mlua::Table>)| {
+                    let fractal = fractal(table)?;
+                    with_point!(lua, point, |point| this.ridged_gradient(point, fractal))
+                },
+            );
+        }
+    }
+
+    /// The `noise` table of the Lua module: `new(kind, seed)` and a `kind(point, seed)` shortcut
+    /// per kind, `seed` defaults to 0.
+    pub(crate) fn module(lua: &mlua::Lua) -> mlua::Result<mlua::Table> {
+        let module = lua.create_table()?;
+        module.set(
+            "new",
+            lua.create_function(|_, (name, seed): (String, Option<u32>)| {
+                Ok(Noise::new(kind(&name)?, seed.unwrap_or(0)))
+            })?,
+        )?;
+        for name in ["perlin", "simplex", "value", "worley"] {
+            let noise_kind = kind(name)?;
+            module.set(
+                name,
+                lua.create_function(move |lua, (point, seed): (mlua::Value, Option<u32>)| {
+                    let noise = Noise::new(noise_kind, seed.unwrap_or(0));
+                    with_point!(lua, point, |point| noise.sample(point))
+                })?,
+            )?;
+        }
+        Ok(module)
+    }
+}
+
+#[cfg(feature = "lua")]
+pub(crate) use lua::module as lua_module;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const KINDS: [Kind; 4] = [Kind::Perlin, Kind::Simplex, Kind::Value, Kind::Worley];
+
+    /// Compares the analytic gradient with central differences.
+    fn assert_gradient<const N: usize, P: Input<N> + core::fmt::Debug>(
+        sample: impl Fn(P) -> (f32, P),
+        point: P,
+    ) {
+        let h = 1e-3;
+        let (_, gradient) = sample(point);
+        for (i, derivative) in gradient.to_array().into_iter().enumerate() {
+            let mut ahead = point.to_array();
+            let mut behind = point.to_array();
+            ahead[i] += h;
+            behind[i] -= h;
+            let difference =
+                (sample(P::from_array(ahead)).0 - sample(P::from_array(behind)).0) / (2. * h);
+            assert!(
+                (difference - derivative).abs() < 2e-2,
+                "{point:?} axis {i}: {difference} != {derivative}"
+            );
+        }
+    }
+
+    #[test]
+    fn seeded() {
+        for kind in KINDS {
+            let point = Vec3::new(1.3, -2.7, 0.4);
+            assert_eq!(
+                Noise::new(kind, 7).sample(point),
+                Noise::new(kind, 7).sample(point)
+            );
+            assert_ne!(
+                Noise::new(kind, 7).sample(point),
+                Noise::new(kind, 8).sample(point)
+            );
+        }
+    }
+
+    #[test]
+    fn perlin_is_zero_on_the_grid() {
+        let noise = Noise::new(Kind::Perlin, 3);
+        assert_eq!(noise.sample(Vec2::new(4., -2.)), 0.);
+        assert_eq!(noise.sample(Vec4::new(1., 2., 3., 4.)), 0.);
+        assert_ne!(noise.sample(Vec2::new(4.5, -2.5)), 0.);
+    }
+
+    #[test]
+    fn ranges() {
+        for kind in [Kind::Perlin, Kind::Simplex, Kind::Value] {
+            let noise = Noise::new(kind, 11);
+            for i in 0..500 {
+                let t = i as f32 * 0.173;
+                let value = noise.sample(Vec3::new(t, t * 0.7 - 3., 5. - t * 0.31));
+                assert!((-1.2..=1.2).contains(&value), "{kind:?} {value}");
+            }
+        }
+        let worley = Noise::new(Kind::Worley, 11);
+        for i in 0..500 {
+            let t = i as f32 * 0.173;
+            let value = worley.sample(Vec2::new(t, -t * 1.3));
+            assert!((0. ..=1.5).contains(&value), "{value}");
+        }
+    }
+
+    #[test]
+    fn gradients() {
+        let points = [
+            Vec3::new(0.3, 0.6, 0.1),
+            Vec3::new(-4.2, 1.75, 3.33),
+            Vec3::new(10.1, -7.45, 0.8),
+        ];
+        for kind in KINDS {
+            let noise = Noise::new(kind, 5);
+            for point in points {
+                assert_gradient(|p: Vec3| noise.gradient(p), point);
+                assert_gradient(|p: Vec2| noise.gradient(p), Vec2::new(point.x, point.y));
+            }
+            assert_gradient(|p: Vec4| noise.gradient(p), Vec4::new(0.3, -1.6, 2.2, 0.45));
+        }
+    }
+
+    #[test]
+    fn fractals() {
+        let noise = Noise::new(Kind::Simplex, 1);
+        let fractal = Fractal::default();
+        let point = Vec2::new(0.37, -1.21);
+        assert_gradient(|p: Vec2| noise.fbm_gradient(p, fractal), point);
+        assert_gradient(|p: Vec2| noise.ridged_gradient(p, fractal), point);
+        let one = Fractal {
+            octaves: 1,
+            ..fractal
+        };
+        assert_eq!(noise.fbm(point, one), noise.sample(point));
+        let ridged = noise.ridged(point, fractal);
+        assert!((0. ..=1.).contains(&ridged));
+    }
+}
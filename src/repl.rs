@@ -0,0 +1,252 @@
+use std::path::PathBuf;
+
+use meth::apply;
+use mlua::{Function, Lua, MultiValue, Table, Value};
+use rustyline::{DefaultEditor, error::ReadlineError};
+
+pub const HISTORY_FILE: &str = ".meth_history";
+const PROMPT: &str = "meth> ";
+const CONTINUATION_PROMPT: &str = "  ... ";
+const HELP: &str = r":help              show this message
+:help <function>   show the annotations of a function, e.g. `vec3`, `lerp` or `Mat2.from_angle`
+:type <expression> show the type and methods of a value
+:quit              leave the repl";
+
+fn history_path() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(HISTORY_FILE))
+}
+
+/// Lines of `value` as printed by the repl, using `tostring` so that `__tostring` is respected.
+fn show(lua: &Lua, values: MultiValue) -> mlua::Result<String> {
+    let tostring = lua.globals().get::<Function>("tostring")?;
+    let shown = values
+        .into_iter()
+        .map(|value| tostring.call::<String>(value))
+        .collect::<mlua::Result<Vec<_>>>()?;
+    Ok(shown.join("\t"))
+}
+
+/// Compiles `input` as an expression if possible, else as a block. Returns `None` while the input
+/// is an incomplete statement that needs more lines.
+fn compile(lua: &Lua, input: &str) -> mlua::Result<Option<Function>> {
+    if let Ok(function) = lua
+        .load(format!("return {input}"))
+        .set_name("=repl")
+        .into_function()
+    {
+        return Ok(Some(function));
+    }
+    match lua.load(input).set_name("=repl").into_function() {
+        Ok(function) => Ok(Some(function)),
+        Err(mlua::Error::SyntaxError {
+            incomplete_input: true,
+            ..
+        }) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// The meth type name of `value` with its method names, or the plain Lua type name.
+fn describe_type(lua: &Lua, value: &Value) -> mlua::Result<String> {
+    let type_name = match value {
+        Value::Table(table) => table.get::<Option<String>>("type").ok().flatten(),
+        _ => None,
+    };
+    let Some(type_name) = type_name else {
+        return Ok(value.type_name().to_owned());
+    };
+    let inner = lua
+        .globals()
+        .get::<Option<Table>>("__inner")?
+        .ok_or_else(|| lua_error::lua_error!("could not get __inner"))?;
+    let meth = mlua::ErrorContext::with_context(inner.get::<Table>("meth"), |_| {
+        "could not get __inner.meth table"
+    })?;
+    let Some(methods) =
+        meth.get::<Option<Table>>(format!("{}_methods", type_name.to_lowercase()))?
+    else {
+        return Ok(type_name);
+    };
+    let mut names = methods
+        .pairs::<String, Value>()
+        .filter_map(|pair| pair.ok())
+        .filter(|(_, value)| value.is_function())
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>();
+    names.sort();
+    Ok(format!("{type_name}\nmethods: {}", names.join(", ")))
+}
+
+/// The LuaLS annotations above the definition of `name` in `source`, followed by the line defining
+/// it. Finds both `function name(` and table entries like `name = function(`.
+pub fn annotations(source: &str, name: &str) -> Option<Vec<String>> {
+    let lines: Vec<&str> = source.lines().collect();
+    let index = lines.iter().position(|line| {
+        let line = line.trim();
+        line.starts_with(&format!("function {name}("))
+            || line.starts_with(&format!("{name} = function("))
+    })?;
+    let mut docs: Vec<String> = lines[..index]
+        .iter()
+        .rev()
+        .map(|line| line.trim())
+        .take_while(|line| line.starts_with("---"))
+        .filter(|line| !line.starts_with("---@diagnostic"))
+        .map(|line| line.trim_start_matches('-').trim().to_owned())
+        .collect();
+    docs.reverse();
+    docs.push(lines[index].trim().to_owned());
+    Some(docs)
+}
+
+/// Help for `Type.name`, `type:name`, a constructor like `vec3` or a bare method name searched in
+/// every type file.
+fn help(name: &str) -> String {
+    let (file, name) = match name.split_once(['.', ':']) {
+        Some((type_name, name)) => (Some(type_name.to_lowercase()), name),
+        None => (None, name),
+    };
+    let mut out = vec![];
+    for (path, source) in meth::TYPE_SOURCES {
+        if let Some(file) = &file
+            && !path.ends_with(&format!("/{file}.lua"))
+        {
+            continue;
+        }
+        if let Some(docs) = annotations(source, name) {
+            out.push(format!("{path}:\n  {}", docs.join("\n  ")));
+        }
+    }
+    if out.is_empty() {
+        format!("no documentation found for `{name}`")
+    } else {
+        out.join("\n")
+    }
+}
+
+fn command(lua: &Lua, line: &str) -> mlua::Result<Option<String>> {
+    let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+    let rest = rest.trim();
+    Ok(Some(match command {
+        ":quit" | ":q" => return Ok(None),
+        ":help" if rest.is_empty() => HELP.to_owned(),
+        ":help" => help(rest),
+        ":type" => {
+            let value = lua
+                .load(format!("return {rest}"))
+                .set_name("=repl")
+                .eval::<Value>()?;
+            describe_type(lua, &value)?
+        }
+        _ => format!("unknown command `{command}`, try :help"),
+    }))
+}
+
+/// Starts an interactive repl on a Lua state with meth installed.
+pub fn run() -> mlua::Result<()> {
+    let mut lua = Lua::new();
+    apply(&mut lua)?;
+
+    let mut editor = DefaultEditor::new()
+        .map_err(|err| lua_error::lua_error!("could not start line editor: {err}"))?;
+    let history = history_path();
+    if let Some(history) = &history {
+        let _ = editor.load_history(history);
+    }
+
+    let mut buffer = String::new();
+    loop {
+        let prompt = if buffer.is_empty() {
+            PROMPT
+        } else {
+            CONTINUATION_PROMPT
+        };
+        let line = match editor.readline(prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => {
+                buffer.clear();
+                continue;
+            }
+            Err(ReadlineError::Eof) => break,
+            Err(err) => return Err(lua_error::lua_error!("could not read line: {err}")),
+        };
+
+        if buffer.is_empty() && line.trim_start().starts_with(':') {
+            let _ = editor.add_history_entry(line.as_str());
+            match command(&lua, line.trim()) {
+                Ok(Some(out)) => println!("{out}"),
+                Ok(None) => break,
+                Err(err) => eprintln!("{err}"),
+            }
+            continue;
+        }
+
+        if !buffer.is_empty() {
+            buffer.push('\n');
+        }
+        buffer.push_str(&line);
+        let result = match compile(&lua, &buffer) {
+            Ok(None) => continue,
+            Ok(Some(function)) => function
+                .call::<MultiValue>(())
+                .and_then(|values| show(&lua, values)),
+            Err(err) => Err(err),
+        };
+        let _ = editor.add_history_entry(buffer.as_str());
+        buffer.clear();
+        match result {
+            Ok(out) if out.is_empty() => {}
+            Ok(out) => println!("{out}"),
+            Err(err) => eprintln!("{err}"),
+        }
+    }
+
+    if let Some(history) = &history {
+        let _ = editor.save_history(history);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn annotations_of_methods_and_functions() {
+        let source = concat!(
+            "local methods = {\n",
+            "\t---@param self meth.Vec2\n",
+            "\t---@return number\n",
+            "\tlength = function(self)\n",
+            "\tend,\n",
+            "}\n",
+            "---@return meth.Vec2\n",
+            "---@diagnostic disable-next-line: lowercase-global\n",
+            "function vec2(x, y)\n",
+            "end\n",
+        );
+        assert_eq!(
+            annotations(source, "length"),
+            Some(vec![
+                "@param self meth.Vec2".to_owned(),
+                "@return number".to_owned(),
+                "length = function(self)".to_owned(),
+            ])
+        );
+        assert_eq!(
+            annotations(source, "vec2"),
+            Some(vec![
+                "@return meth.Vec2".to_owned(),
+                "function vec2(x, y)".to_owned(),
+            ])
+        );
+        assert_eq!(annotations(source, "missing"), None);
+    }
+
+    #[test]
+    fn help_examples_are_documented() {
+        for name in ["vec3", "lerp", "Mat2.from_angle"] {
+            assert!(!help(name).starts_with("no documentation"), "{name}");
+        }
+    }
+}
@@ -0,0 +1,404 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use mlua::{Function, Lua, Table};
+
+use crate::{
+    coverage::Coverage,
+    hooks::{self, Limits},
+};
+
+pub const DEFAULT_TEST_DIR: &str = "src/lua/tests";
+pub const LOPA_TEST_MODULE: &str = "lopa-test";
+pub const DEFAULT_COVERAGE_FILE: &str = "lcov.info";
+pub const DEFAULT_TEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub update_snapshots: bool,
+    pub seed: Option<i64>,
+    pub test_dir: PathBuf,
+    /// Runs every spec file in its own Lua state on this many threads.
+    pub test_threads: Option<usize>,
+    /// Line hits collected from every test state when running with `--coverage`.
+    pub coverage: Option<Arc<Mutex<Coverage>>>,
+    pub coverage_file: PathBuf,
+    /// Limits of every test that does not set its own in `test_module`.
+    pub timeout: Option<Duration>,
+    pub max_instructions: Option<u64>,
+    /// Reruns the affected spec files whenever a watched Lua file changes.
+    pub watch: bool,
+    /// Extra files or directories watched with `--watch`, such as user scripts.
+    pub watch_paths: Vec<PathBuf>,
+    /// Loads the meth type files from below this directory instead of the embedded copies.
+    pub meth_root: Option<PathBuf>,
+}
+
+impl Options {
+    pub fn from_args(args: &[String]) -> Self {
+        Self {
+            update_snapshots: args.iter().any(|arg| arg == "--update-snapshots"),
+            seed: flag_value(args, "--seed").and_then(|seed| seed.parse().ok()),
+            test_dir: flag_value(args, "--test-dir")
+                .unwrap_or(DEFAULT_TEST_DIR)
+                .into(),
+            test_threads: flag_value(args, "--test-threads")
+                .and_then(|threads| threads.parse().ok()),
+            coverage: args
+                .iter()
+                .any(|arg| arg == "--coverage")
+                .then(Default::default),
+            coverage_file: flag_value(args, "--coverage-file")
+                .unwrap_or(DEFAULT_COVERAGE_FILE)
+                .into(),
+            timeout: match flag_value(args, "--test-timeout") {
+                Some(seconds) => seconds
+                    .parse()
+                    .ok()
+                    .filter(|seconds: &f64| *seconds > 0.0)
+                    .map(Duration::from_secs_f64),
+                None => Some(DEFAULT_TEST_TIMEOUT),
+            },
+            max_instructions: flag_value(args, "--test-instructions")
+                .and_then(|instructions| instructions.parse().ok()),
+            watch: args.iter().any(|arg| arg == "--watch"),
+            watch_paths: args
+                .windows(2)
+                .filter(|pair| pair[0] == "--watch-path")
+                .map(|pair| pair[1].clone().into())
+                .collect(),
+            meth_root: None,
+        }
+    }
+}
+
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|arg| arg == flag)
+        .and_then(|i| args.get(i + 1))
+        .map(String::as_str)
+}
+
+/// Adds the host functions lopa-test needs on top of `lopa_test::apply`.
+pub fn install(
+    lua: &Lua,
+    options: &Options,
+    limits: Arc<Mutex<Option<Limits>>>,
+) -> mlua::Result<()> {
+    let inner = lua
+        .globals()
+        .get::<Option<Table>>("__inner")?
+        .ok_or_else(|| lua_error::lua_error!("could not get __inner"))?;
+    let lopa_test = mlua::ErrorContext::with_context(inner.get::<Table>("lopa_test"), |_| {
+        "could not get __inner.lopa_test table"
+    })?;
+
+    lopa_test.set("update_snapshots", options.update_snapshots)?;
+    lopa_test.set("property_seed", options.seed)?;
+    lopa_test.set(
+        "timeout",
+        options.timeout.map(|timeout| timeout.as_secs_f64()),
+    )?;
+    lopa_test.set("max_instructions", options.max_instructions)?;
+    lopa_test.set("timeout_prefix", hooks::TIMEOUT_PREFIX)?;
+    lopa_test.set("set_limits", {
+        let limits = limits.clone();
+        lua.create_function(
+            move |_, (timeout, max_instructions): (Option<f64>, Option<u64>)| {
+                *limits.lock().unwrap() = Some(Limits::new(
+                    timeout.and_then(|timeout| Duration::try_from_secs_f64(timeout).ok()),
+                    max_instructions,
+                ));
+                Ok(())
+            },
+        )?
+    })?;
+    lopa_test.set(
+        "clear_limits",
+        lua.create_function(move |_, ()| {
+            *limits.lock().unwrap() = None;
+            Ok(())
+        })?,
+    )?;
+    lopa_test.set(
+        "create_dir_all",
+        lua.create_function(|_, path: String| {
+            std::fs::create_dir_all(&path)
+                .map_err(|err| lua_error::lua_error!("could not create directory `{path}`: {err}"))
+        })?,
+    )?;
+    lopa_test.set(
+        "read_file",
+        lua.create_function(|lua, path: String| match std::fs::read(&path) {
+            Ok(contents) => lua.create_string(contents).map(Some),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(lua_error::lua_error!("could not read `{path}`: {err}")),
+        })?,
+    )?;
+    lopa_test.set(
+        "write_file",
+        lua.create_function(|_, (path, contents): (String, mlua::String)| {
+            std::fs::write(&path, &*contents.as_bytes())
+                .map_err(|err| lua_error::lua_error!("could not write snapshot `{path}`: {err}"))
+        })?,
+    )?;
+    Ok(())
+}
+
+/// Exposes `FromLua`/`IntoLua` round trips of the vector and bounds types as `__inner.meth_conversions`,
+/// so that specs can check how Rust converts Lua values.
+pub fn install_conversions(lua: &Lua) -> mlua::Result<()> {
+    fn roundtrip<T: mlua::FromLua + mlua::IntoLua + 'static>(lua: &Lua) -> mlua::Result<Function> {
+        lua.create_function(|_, value: T| Ok(value))
+    }
+
+    let conversions = lua.create_table()?;
+    conversions.set("vec2", roundtrip::<meth::vec2::Vec2>(lua)?)?;
+    conversions.set("ivec2", roundtrip::<meth::vec2::IVec2>(lua)?)?;
+    conversions.set("vec3", roundtrip::<meth::vec3::Vec3>(lua)?)?;
+    conversions.set("ivec3", roundtrip::<meth::vec3::IVec3>(lua)?)?;
+    conversions.set("vec4", roundtrip::<meth::vec4::Vec4>(lua)?)?;
+    conversions.set("ivec4", roundtrip::<meth::vec4::IVec4>(lua)?)?;
+    conversions.set("rect", roundtrip::<meth::bounds::Rect>(lua)?)?;
+    conversions.set("irect", roundtrip::<meth::bounds::IRect>(lua)?)?;
+    conversions.set("aabb3", roundtrip::<meth::bounds::Aabb3>(lua)?)?;
+    conversions.set("ray2", roundtrip::<meth::geometry::Ray2>(lua)?)?;
+    conversions.set("ray3", roundtrip::<meth::geometry::Ray3>(lua)?)?;
+    conversions.set("plane", roundtrip::<meth::geometry::Plane>(lua)?)?;
+    conversions.set("sphere", roundtrip::<meth::geometry::Sphere>(lua)?)?;
+    conversions.set("circle", roundtrip::<meth::geometry::Circle>(lua)?)?;
+    conversions.set("segment2", roundtrip::<meth::geometry::Segment2>(lua)?)?;
+    conversions.set("segment3", roundtrip::<meth::geometry::Segment3>(lua)?)?;
+
+    let inner = lua
+        .globals()
+        .get::<Option<Table>>("__inner")?
+        .ok_or_else(|| lua_error::lua_error!("could not get __inner"))?;
+    inner.set("meth_conversions", conversions)
+}
+
+/// Lua files under `dir`, sorted so that modules are always registered in the same order.
+pub fn spec_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "lua") {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Runs every spec file in `dir`, registering their test modules.
+pub fn load_specs(lua: &Lua, dir: &Path) -> mlua::Result<()> {
+    let files = spec_files(dir).map_err(|err| {
+        lua_error::lua_error!("could not read test directory `{}`: {err}", dir.display())
+    })?;
+    for file in files {
+        lua.load(file.as_path()).exec()?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub struct TestFailure {
+    pub case_name: Option<String>,
+    pub error: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct TestResult {
+    pub full_name: String,
+    pub passed: bool,
+    pub timed_out: bool,
+    pub failures: Vec<TestFailure>,
+}
+
+impl mlua::FromLua for TestFailure {
+    fn from_lua(value: mlua::Value, _lua: &Lua) -> mlua::Result<Self> {
+        let table = value.as_table().ok_or_else(|| {
+            lua_error::lua_error!(
+                "`TestFailure` should be a table, got `{}` instead",
+                value.type_name()
+            )
+        })?;
+        Ok(Self {
+            case_name: table.get("case_name")?,
+            error: table.get("error")?,
+        })
+    }
+}
+
+impl mlua::IntoLua for TestFailure {
+    fn into_lua(self, lua: &Lua) -> mlua::Result<mlua::Value> {
+        let table = lua.create_table()?;
+        table.set("case_name", self.case_name)?;
+        table.set("error", self.error)?;
+        Ok(mlua::Value::Table(table))
+    }
+}
+
+impl mlua::FromLua for TestResult {
+    fn from_lua(value: mlua::Value, _lua: &Lua) -> mlua::Result<Self> {
+        let table = value.as_table().ok_or_else(|| {
+            lua_error::lua_error!(
+                "`TestResult` should be a table, got `{}` instead",
+                value.type_name()
+            )
+        })?;
+        Ok(Self {
+            full_name: table.get("full_name")?,
+            passed: table.get("passed")?,
+            timed_out: table.get("timed_out")?,
+            failures: table.get("failures")?,
+        })
+    }
+}
+
+impl mlua::IntoLua for TestResult {
+    fn into_lua(self, lua: &Lua) -> mlua::Result<mlua::Value> {
+        let table = lua.create_table()?;
+        table.set("full_name", self.full_name)?;
+        table.set("passed", self.passed)?;
+        table.set("timed_out", self.timed_out)?;
+        table.set("failures", self.failures)?;
+        Ok(mlua::Value::Table(table))
+    }
+}
+
+pub fn lopa_test_module(lua: &Lua) -> mlua::Result<Table> {
+    lua.globals()
+        .get::<Function>("require")?
+        .call(LOPA_TEST_MODULE)
+}
+
+/// A fresh Lua state with meth and lopa-test installed.
+pub fn new_state(options: &Options) -> mlua::Result<Lua> {
+    let mut lua = Lua::new();
+    let limits = Arc::new(Mutex::new(None));
+    hooks::install(&lua, options.coverage.clone(), limits.clone())?;
+    match &options.meth_root {
+        Some(root) => meth::apply_from_dir(&mut lua, root)?,
+        None => meth::apply(&mut lua)?,
+    }
+    lopa_test::apply(&lua)?;
+    install(&lua, options, limits)?;
+    install_conversions(&lua)?;
+    Ok(lua)
+}
+
+/// Runs one spec file in a fresh Lua state. Errors while loading it are reported as a failed
+/// test named after the file.
+pub fn run_spec(file: &Path, options: &Options) -> Vec<TestResult> {
+    let run = || -> mlua::Result<Vec<TestResult>> {
+        let lua = new_state(options)?;
+        lua.load(file).exec()?;
+        lopa_test_module(&lua)?
+            .get::<Function>("run_tests")?
+            .call(())
+    };
+    run().unwrap_or_else(|err| {
+        vec![TestResult {
+            full_name: file.display().to_string(),
+            passed: false,
+            timed_out: false,
+            failures: vec![TestFailure {
+                case_name: None,
+                error: err.to_string(),
+            }],
+        }]
+    })
+}
+
+/// Runs every spec file in a fresh Lua state, spreading the files over `threads` threads.
+/// Results are reported in spec file order. Returns whether all tests passed.
+pub fn run_isolated(options: &Options, threads: usize) -> mlua::Result<bool> {
+    let files = spec_files(&options.test_dir).map_err(|err| {
+        lua_error::lua_error!(
+            "could not read test directory `{}`: {err}",
+            options.test_dir.display()
+        )
+    })?;
+
+    let start = Instant::now();
+    let next = AtomicUsize::new(0);
+    let mut modules: Vec<(usize, Vec<TestResult>)> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads.max(1))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = vec![];
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(file) = files.get(index) else {
+                            break;
+                        };
+                        done.push((index, run_spec(file, options)));
+                    }
+                    done
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("test worker panicked"))
+            .collect()
+    });
+    let elapsed = start.elapsed();
+
+    modules.sort_by_key(|(index, _)| *index);
+    let results: Vec<TestResult> = modules
+        .into_iter()
+        .flat_map(|(_, results)| results)
+        .collect();
+    let passed = results.iter().all(|result| result.passed);
+
+    let lua = new_state(&Options {
+        coverage: None,
+        ..options.clone()
+    })?;
+    lopa_test_module(&lua)?
+        .get::<Function>("report")?
+        .call::<()>((results, elapsed.as_secs_f64()))?;
+    Ok(passed)
+}
+
+/// Runs the spec files as configured by `options`, then writes the coverage report if requested.
+/// Returns whether all tests passed.
+pub fn run(options: &Options) -> mlua::Result<bool> {
+    let passed = match options.test_threads {
+        Some(threads) => run_isolated(options, threads)?,
+        None => {
+            let lua = new_state(options)?;
+            load_specs(&lua, &options.test_dir)?;
+            lopa_test_module(&lua)?
+                .get::<Function>("execute_tests")?
+                .call(())?
+        }
+    };
+
+    if let Some(coverage) = &options.coverage {
+        let coverage = coverage.lock().unwrap();
+        coverage.write_lcov(&options.coverage_file).map_err(|err| {
+            lua_error::lua_error!(
+                "could not write coverage to `{}`: {err}",
+                options.coverage_file.display()
+            )
+        })?;
+        println!();
+        println!("{}", coverage.summary());
+        println!("coverage written to {}", options.coverage_file.display());
+    }
+    Ok(passed)
+}
@@ -0,0 +1,121 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use mlua::{Function, Lua, MultiValue, Table, Value};
+
+use crate::{hooks, runner};
+
+pub const USAGE: &str = "usage: meth run [--lopa-test] <file.lua> [args...]";
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub script: PathBuf,
+    pub args: Vec<String>,
+    /// Also installs lopa-test, so that scripts can run tests.
+    pub lopa_test: bool,
+}
+
+impl Options {
+    /// Parses the arguments following `run`. Flags are only read before the script path, every
+    /// argument after it is passed on to the script.
+    pub fn from_args(args: &[String]) -> Option<Self> {
+        let mut lopa_test = false;
+        let mut args = args.iter();
+        let script = loop {
+            match args.next()?.as_str() {
+                "--lopa-test" => lopa_test = true,
+                script => break script.into(),
+            }
+        };
+        Some(Self {
+            script,
+            args: args.cloned().collect(),
+            lopa_test,
+        })
+    }
+}
+
+/// Prepends `?.lua` and `?/init.lua` below the script's directory to `package.path`, so that
+/// scripts can require their siblings from any working directory.
+fn set_package_path(lua: &Lua, script: &Path) -> mlua::Result<()> {
+    let dir = script
+        .parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let package = lua.globals().get::<Table>("package")?;
+    let path = package.get::<String>("path")?;
+    package.set(
+        "path",
+        format!("{dir}/?.lua;{dir}/?/init.lua;{path}", dir = dir.display()),
+    )
+}
+
+/// `arg` as set up by the standalone Lua interpreter: the script at 0, the interpreter before it
+/// and the script arguments from 1.
+fn set_arg(lua: &Lua, program: &str, options: &Options) -> mlua::Result<()> {
+    let arg = lua.create_table()?;
+    arg.raw_set(-1, program)?;
+    arg.raw_set(0, options.script.display().to_string())?;
+    for (i, value) in options.args.iter().enumerate() {
+        arg.raw_set(i + 1, value.as_str())?;
+    }
+    lua.globals().set("arg", arg)
+}
+
+/// Runs the script described by `options`. Lua errors are printed with a traceback. Returns
+/// whether the script ran without errors.
+pub fn run(program: &str, options: &Options) -> mlua::Result<bool> {
+    let mut lua = Lua::new();
+    // the hooks enforce the limits of the tests, like in the test runner
+    let limits = Arc::new(Mutex::new(None));
+    if options.lopa_test {
+        hooks::install(&lua, None, limits.clone())?;
+    }
+    meth::apply(&mut lua)?;
+    if options.lopa_test {
+        lopa_test::apply(&lua)?;
+        runner::install(&lua, &runner::Options::from_args(&[]), limits)?;
+    }
+    set_package_path(&lua, &options.script)?;
+    set_arg(&lua, program, options)?;
+
+    let chunk = match lua.load(options.script.as_path()).into_function() {
+        Ok(chunk) => chunk,
+        Err(err) => {
+            eprintln!("{err}");
+            return Ok(false);
+        }
+    };
+    let protected: Function = lua
+        .load(
+            r"
+local fun = ...
+return function(...)
+	return xpcall(fun, debug.traceback, ...)
+end
+            ",
+        )
+        .set_name("=meth run")
+        .call(chunk)?;
+    let mut results = protected
+        .call::<MultiValue>(MultiValue::from_iter(
+            options
+                .args
+                .iter()
+                .map(|arg| lua.create_string(arg).map(Value::String))
+                .collect::<mlua::Result<Vec<_>>>()?,
+        ))?
+        .into_iter();
+    if let Some(Value::Boolean(true)) = results.next() {
+        return Ok(true);
+    }
+    let traceback = match results.next() {
+        Some(Value::String(traceback)) => traceback.to_string_lossy(),
+        Some(other) => format!("{other:?}"),
+        None => "unknown error".to_owned(),
+    };
+    eprintln!("{traceback}");
+    Ok(false)
+}
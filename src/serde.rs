@@ -0,0 +1,387 @@
+//! Serde support for the vector and matrix types.
+//!
+//! By default they serialize as structs, `{"x":1.0,"y":2.0}` and matrices as their axes. The
+//! [`compact`] form is a sequence, `[1.0,2.0]` and matrices flat and column-major, like the Lua
+//! side uses. Vectors deserialize from both forms, a flat matrix only through [`compact`]. Choose
+//! the form per field with `#[serde(with)]`:
+//!
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Spawn {
+//!     #[serde(with = "meth::serde::compact")]
+//!     position: meth::vec3::Vec3,
+//!     #[serde(with = "meth::serde::structured")]
+//!     transform: meth::mat4::Mat4,
+//! }
+//! ```
+
+use core::fmt;
+
+use ::serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, DeserializeSeed, Expected, MapAccess, SeqAccess},
+    ser::{SerializeStruct, SerializeTuple},
+};
+
+use crate::{
+    mat2::Mat2,
+    mat3::Mat3,
+    mat4::Mat4,
+    vec2::{IVec2, UVec2, Vec2},
+    vec3::{IVec3, UVec3, Vec3},
+    vec4::{IVec4, UVec4, Vec4},
+};
+
+/// Types with a compact sequence form next to their struct form.
+pub trait Compact: Serialize + Sized {
+    fn serialize_compact<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+    fn deserialize_compact<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// Serializes as a sequence, `[1.0,2.0]` for vectors and flat column-major for matrices.
+pub mod compact {
+    use ::serde::{Deserializer, Serializer};
+
+    use super::Compact;
+
+    pub fn serialize<T: Compact, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize_compact(serializer)
+    }
+
+    pub fn deserialize<'de, T: Compact, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::deserialize_compact(deserializer)
+    }
+}
+
+/// Serializes as a struct, the default of the types, `{"x":1.0,"y":2.0}` for vectors.
+pub mod structured {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<T: Serialize, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    /// Vectors accept the compact form as well.
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::deserialize(deserializer)
+    }
+}
+
+/// Resolves a map key to one of the struct fields.
+struct Field(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for Field {
+    type Value = &'static str;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl de::Visitor<'_> for Field {
+    type Value = &'static str;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "one of the fields {:?}", self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        self.0
+            .iter()
+            .find(|field| **field == value)
+            .copied()
+            .ok_or_else(|| E::unknown_field(value, self.0))
+    }
+}
+
+/// Next element of a sequence that should hold `index + 1` elements or more.
+fn element<'de, A: SeqAccess<'de>, T: Deserialize<'de>>(
+    seq: &mut A,
+    index: usize,
+    expected: &dyn Expected,
+) -> Result<T, A::Error> {
+    seq.next_element()?
+        .ok_or_else(|| de::Error::invalid_length(index, expected))
+}
+
+/// Fails if a sequence of `len` elements has more of them.
+fn end<'de, A: SeqAccess<'de>>(
+    seq: &mut A,
+    len: usize,
+    expected: &dyn Expected,
+) -> Result<(), A::Error> {
+    match seq.next_element::<de::IgnoredAny>()? {
+        Some(_) => Err(de::Error::invalid_length(len + 1, expected)),
+        None => Ok(()),
+    }
+}
+
+fn flat<'de, A: SeqAccess<'de>, T: Deserialize<'de> + Default + Copy, const N: usize>(
+    mut seq: A,
+    expected: &dyn Expected,
+) -> Result<[T; N], A::Error> {
+    let mut array = [T::default(); N];
+    for (index, value) in array.iter_mut().enumerate() {
+        *value = element(&mut seq, index, expected)?;
+    }
+    end(&mut seq, N, expected)?;
+    Ok(array)
+}
+
+macro_rules! impl_serde {
+    (
+        $name:ident, $scalar:ty, $len:literal, [$($field:ident: $field_type:ty),+],
+        |$value:ident| $to_array:expr, |$array:ident| $from_array:expr
+    ) => {
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let len = [$(stringify!($field)),+].len();
+                let mut state = serializer.serialize_struct(stringify!($name), len)?;
+                $(state.serialize_field(stringify!($field), &self.$field)?;)+
+                state.end()
+            }
+        }
+
+        impl Compact for $name {
+            fn serialize_compact<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let $value = self;
+                let mut state = serializer.serialize_tuple($len)?;
+                for element in $to_array {
+                    state.serialize_element(&element)?;
+                }
+                state.end()
+            }
+
+            fn deserialize_compact<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                Self::deserialize_form(deserializer, true)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Self::deserialize_form(deserializer, false)
+            }
+        }
+
+        impl $name {
+            /// A sequence holds the fields in order, or all scalars flat in the `compact` form.
+            fn deserialize_form<'de, D: Deserializer<'de>>(
+                deserializer: D,
+                compact: bool,
+            ) -> Result<Self, D::Error> {
+                const FIELDS: &[&str] = &[$(stringify!($field)),+];
+
+                struct Visitor {
+                    compact: bool,
+                }
+
+                impl<'de> de::Visitor<'de> for Visitor {
+                    type Value = $name;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        if self.compact {
+                            write!(
+                                formatter,
+                                "`{}` as a sequence of {} numbers",
+                                stringify!($name),
+                                $len
+                            )
+                        } else {
+                            write!(
+                                formatter,
+                                "`{}` as a struct or a sequence of its fields",
+                                stringify!($name)
+                            )
+                        }
+                    }
+
+                    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                        if self.compact {
+                            let $array: [$scalar; $len] = flat(seq, &self)?;
+                            return Ok($from_array);
+                        }
+                        let mut index = 0;
+                        $(
+                            let $field: $field_type = element(&mut seq, index, &self)?;
+                            index += 1;
+                        )+
+                        end(&mut seq, index, &self)?;
+                        Ok($name { $($field),+ })
+                    }
+
+                    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                        $(let mut $field: Option<$field_type> = None;)+
+                        while let Some(key) = map.next_key_seed(Field(FIELDS))? {
+                            $(
+                                if key == stringify!($field) {
+                                    if $field.is_some() {
+                                        return Err(de::Error::duplicate_field(stringify!($field)));
+                                    }
+                                    $field = Some(map.next_value()?);
+                                }
+                            )+
+                        }
+                        Ok($name {
+                            $(
+                                $field: $field
+                                    .ok_or_else(|| de::Error::missing_field(stringify!($field)))?,
+                            )+
+                        })
+                    }
+                }
+
+                if compact {
+                    deserializer.deserialize_tuple($len, Visitor { compact })
+                } else {
+                    deserializer.deserialize_struct(stringify!($name), FIELDS, Visitor { compact })
+                }
+            }
+        }
+    };
+}
+
+impl_serde!(UVec2, u32, 2, [x: u32, y: u32], |v| [v.x, v.y], |a| UVec2::new(a[0], a[1]));
+impl_serde!(IVec2, i32, 2, [x: i32, y: i32], |v| [v.x, v.y], |a| IVec2::new(a[0], a[1]));
+impl_serde!(Vec2, f32, 2, [x: f32, y: f32], |v| [v.x, v.y], |a| Vec2::new(a[0], a[1]));
+impl_serde!(
+    UVec3, u32, 3, [x: u32, y: u32, z: u32],
+    |v| [v.x, v.y, v.z], |a| UVec3::new(a[0], a[1], a[2])
+);
+impl_serde!(
+    IVec3, i32, 3, [x: i32, y: i32, z: i32],
+    |v| [v.x, v.y, v.z], |a| IVec3::new(a[0], a[1], a[2])
+);
+impl_serde!(
+    Vec3, f32, 3, [x: f32, y: f32, z: f32],
+    |v| [v.x, v.y, v.z], |a| Vec3::new(a[0], a[1], a[2])
+);
+impl_serde!(
+    UVec4, u32, 4, [x: u32, y: u32, z: u32, w: u32],
+    |v| [v.x, v.y, v.z, v.w], |a| UVec4::new(a[0], a[1], a[2], a[3])
+);
+impl_serde!(
+    IVec4, i32, 4, [x: i32, y: i32, z: i32, w: i32],
+    |v| [v.x, v.y, v.z, v.w], |a| IVec4::new(a[0], a[1], a[2], a[3])
+);
+impl_serde!(
+    Vec4, f32, 4, [x: f32, y: f32, z: f32, w: f32],
+    |v| [v.x, v.y, v.z, v.w], |a| Vec4::new(a[0], a[1], a[2], a[3])
+);
+impl_serde!(
+    Mat2, f32, 4, [x_axis: Vec2, y_axis: Vec2],
+    |m| m.to_cols_array(), |a| Mat2::from_cols_array(&a)
+);
+impl_serde!(
+    Mat3, f32, 9, [x_axis: Vec3, y_axis: Vec3, z_axis: Vec3],
+    |m| m.to_cols_array(), |a| Mat3::from_cols_array(&a)
+);
+impl_serde!(
+    Mat4, f32, 16, [x_axis: Vec4, y_axis: Vec4, z_axis: Vec4, w_axis: Vec4],
+    |m| m.to_cols_array(), |a| Mat4::from_cols_array(&a)
+);
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        mat2::Mat2,
+        mat3::Mat3,
+        vec2::Vec2,
+        vec3::{IVec3, Vec3},
+    };
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Spawn {
+        #[serde(with = "crate::serde::compact")]
+        position: Vec3,
+        #[serde(with = "crate::serde::structured")]
+        offset: IVec3,
+        #[serde(with = "crate::serde::compact")]
+        transform: Mat2,
+    }
+
+    #[test]
+    fn struct_form_by_default() {
+        assert_eq!(
+            serde_json::to_string(&Vec2::new(1., 2.)).unwrap(),
+            r#"{"x":1.0,"y":2.0}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&Mat2::new(1., 2., 3., 4.)).unwrap(),
+            r#"{"x_axis":{"x":1.0,"y":2.0},"y_axis":{"x":3.0,"y":4.0}}"#
+        );
+    }
+
+    #[test]
+    fn compact_per_field() {
+        let spawn = Spawn {
+            position: Vec3::new(1., 2., 3.),
+            offset: IVec3::new(-1, 0, 1),
+            transform: Mat2::new(1., 2., 3., 4.),
+        };
+        let json = serde_json::to_string(&spawn).unwrap();
+        assert_eq!(
+            json,
+            r#"{"position":[1.0,2.0,3.0],"offset":{"x":-1,"y":0,"z":1},"transform":[1.0,2.0,3.0,4.0]}"#
+        );
+        assert_eq!(serde_json::from_str::<Spawn>(&json).unwrap(), spawn);
+    }
+
+    #[test]
+    fn deserializes_both_forms() {
+        assert_eq!(
+            serde_json::from_str::<Vec3>("[1, 2, 3]").unwrap(),
+            serde_json::from_str::<Vec3>(r#"{"z": 3, "x": 1, "y": 2}"#).unwrap(),
+        );
+        let mut deserializer = serde_json::Deserializer::from_str("[1, 2, 3, 4, 5, 6, 7, 8, 9]");
+        let flat: Mat3 = crate::serde::compact::deserialize(&mut deserializer).unwrap();
+        assert_eq!(flat.to_cols_array(), [1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        assert_eq!(flat.y_axis, Vec3::new(4., 5., 6.));
+        let axes =
+            r#"{"x_axis": [1, 2, 3], "y_axis": {"x": 4, "y": 5, "z": 6}, "z_axis": [7, 8, 9]}"#;
+        assert_eq!(serde_json::from_str::<Mat3>(axes).unwrap(), flat);
+        let sequence = "[[1, 2, 3], [4, 5, 6], [7, 8, 9]]";
+        assert_eq!(serde_json::from_str::<Mat3>(sequence).unwrap(), flat);
+    }
+
+    #[test]
+    fn rejects_malformed() {
+        assert!(serde_json::from_str::<Vec3>("[1, 2]").is_err());
+        assert!(serde_json::from_str::<Vec2>("[1, 2, 3]").is_err());
+        assert!(serde_json::from_str::<Vec2>(r#"{"x": 1}"#).is_err());
+        assert!(serde_json::from_str::<Vec2>(r#"{"x": 1, "y": 2, "z": 3}"#).is_err());
+        assert!(serde_json::from_str::<Vec2>(r#"{"x": 1, "x": 2}"#).is_err());
+        assert!(serde_json::from_str::<Mat2>("[1, 2, 3, 4]").is_err());
+        assert!(serde_json::from_str::<Mat2>("[[1, 2], [3, 4], [5, 6]]").is_err());
+        let mut deserializer = serde_json::Deserializer::from_str("[1, 2, 3, 4, 5]");
+        assert!(crate::serde::compact::deserialize::<Mat2, _>(&mut deserializer).is_err());
+    }
+
+    #[test]
+    fn round_trips_without_self_description() {
+        let matrix = Mat3::from_cols_array(&[1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+        let bytes = bincode::serialize(&matrix).unwrap();
+        assert_eq!(bincode::deserialize::<Mat3>(&bytes).unwrap(), matrix);
+
+        let spawn = Spawn {
+            position: Vec3::new(1., 2., 3.),
+            offset: IVec3::new(-1, 0, 1),
+            transform: Mat2::new(1., 2., 3., 4.),
+        };
+        let bytes = bincode::serialize(&spawn).unwrap();
+        assert_eq!(bincode::deserialize::<Spawn>(&bytes).unwrap(), spawn);
+    }
+}
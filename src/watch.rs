@@ -0,0 +1,132 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::runner::{self, Options, TestResult};
+
+/// Checkout the embedded Lua files are reloaded from on every run, independent of the working
+/// directory. Files missing there fall back to the embedded ones.
+pub const METH_ROOT: &str = env!("CARGO_MANIFEST_DIR");
+/// Directory of the meth type files below `METH_ROOT`.
+pub const METH_DIR: &str = "src/lua/meth";
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification times of every watched Lua file.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+fn snapshot(roots: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+    for root in roots {
+        let files = if root.is_dir() {
+            runner::spec_files(root).unwrap_or_default()
+        } else {
+            vec![root.clone()]
+        };
+        for file in files {
+            if let Ok(modified) = std::fs::metadata(&file).and_then(|meta| meta.modified()) {
+                snapshot.insert(file, modified);
+            }
+        }
+    }
+    snapshot
+}
+
+/// Files that were added, removed or modified between two snapshots.
+fn changed(old: &Snapshot, new: &Snapshot) -> BTreeSet<PathBuf> {
+    old.keys()
+        .chain(new.keys())
+        .filter(|path| old.get(*path) != new.get(*path))
+        .cloned()
+        .collect()
+}
+
+/// Spec files to rerun after `changed` files changed. Edits inside the test directory only rerun
+/// the changed specs, anything else may affect every spec.
+fn affected(test_dir: &Path, changed: &BTreeSet<PathBuf>) -> std::io::Result<Vec<PathBuf>> {
+    let specs = runner::spec_files(test_dir)?;
+    if changed.iter().all(|path| path.starts_with(test_dir)) {
+        Ok(specs
+            .into_iter()
+            .filter(|spec| changed.contains(spec))
+            .collect())
+    } else {
+        Ok(specs)
+    }
+}
+
+fn print_summary(results: &[TestResult], elapsed: Duration) {
+    let failed: Vec<_> = results.iter().filter(|result| !result.passed).collect();
+    for result in &failed {
+        let status = if result.timed_out { "TIMEOUT" } else { "FAIL" };
+        let error = result
+            .failures
+            .first()
+            .and_then(|failure| failure.error.lines().next())
+            .unwrap_or_default();
+        println!("  {status} {}: {error}", result.full_name);
+    }
+    println!(
+        "{} passed, {} failed in {:.2}s",
+        results.len() - failed.len(),
+        failed.len(),
+        elapsed.as_secs_f64()
+    );
+}
+
+fn run_files(files: &[PathBuf], options: &Options) {
+    let start = Instant::now();
+    let results: Vec<TestResult> = files
+        .iter()
+        .flat_map(|file| runner::run_spec(file, options))
+        .collect();
+    print_summary(&results, start.elapsed());
+}
+
+/// Runs every spec once, then polls the Lua sources and reruns the affected specs in fresh
+/// states whenever something changes. Only returns on errors.
+pub fn run(options: &Options) -> mlua::Result<()> {
+    let options = Options {
+        coverage: None,
+        meth_root: Some(PathBuf::from(METH_ROOT)),
+        ..options.clone()
+    };
+    let mut roots = vec![
+        Path::new(METH_ROOT).join(METH_DIR),
+        options.test_dir.clone(),
+    ];
+    roots.extend(options.watch_paths.iter().cloned());
+
+    let test_dir_error = |err: std::io::Error| {
+        lua_error::lua_error!(
+            "could not read test directory `{}`: {err}",
+            options.test_dir.display()
+        )
+    };
+
+    let mut last = snapshot(&roots);
+    println!("watch: running all specs");
+    run_files(
+        &runner::spec_files(&options.test_dir).map_err(test_dir_error)?,
+        &options,
+    );
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        let current = snapshot(&roots);
+        let changed = changed(&last, &current);
+        last = current;
+        if changed.is_empty() {
+            continue;
+        }
+
+        let files = affected(&options.test_dir, &changed).map_err(test_dir_error)?;
+        println!();
+        println!(
+            "watch: {} file(s) changed, running {} spec file(s)",
+            changed.len(),
+            files.len()
+        );
+        run_files(&files, &options);
+    }
+}